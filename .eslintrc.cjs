@@ -36,9 +36,9 @@ const config = {
   ignorePatterns: [
     'node_modules',
     'dist',
-    'taurpc',
     'target',
     'example',
+    'taurpc/tests/snapshots',
   ],
 }
 
@@ -0,0 +1,1222 @@
+//! Serialization of 64 and 128-bit integers, these can't be represented exactly by a JavaScript `number`.
+//!
+//! Depending on the [`BigIntExport`] policy, large integers are serialized as strings so they survive `JSON.parse`
+//! on the frontend. With those policies, arguments are deserialized leniently, so large integers can be sent as
+//! numbers or strings.
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::ser::{self, Serialize, Serializer};
+use serde_json::Value;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use tauri::ipc::{CommandArg, CommandItem, Invoke, InvokeError, InvokeResponseBody, IpcResponse};
+use tauri::{Manager, Runtime};
+
+/// How 64 and 128-bit integers (`i64`, `u64`, `i128`, `u128`, `isize` and `usize`) are represented on the frontend.
+///
+/// Configure this with [`Router::bigint`](crate::Router::bigint), the exported types and the serialization on the
+/// Rust side will match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BigIntExport {
+    /// Export as `number`, values outside of `Number.MAX_SAFE_INTEGER` lose precision.
+    /// Enable [`Router::bigint_guard`](crate::Router::bigint_guard) to return an error instead.
+    #[default]
+    Number,
+    /// Export as `bigint`, the values are revived by the generated client.
+    BigInt,
+    /// Export as `string`.
+    String,
+}
+
+const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+/// How a router serializes 64 and 128-bit integers, see [`Router::bigint`](crate::Router::bigint) and
+/// [`Router::bigint_guard`](crate::Router::bigint_guard).
+///
+/// The router manages its policy in the state of the app, where it is read by the handlers and event triggers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BigIntPolicy {
+    /// How the integers are represented on the frontend.
+    pub mode: BigIntExport,
+    /// Return an error instead of losing precision, only used with [`BigIntExport::Number`].
+    pub guard: bool,
+}
+
+impl BigIntPolicy {
+    fn is_default(&self) -> bool {
+        self.mode == BigIntExport::Number && !self.guard
+    }
+
+    /// The policy that is managed by the app, or the default policy.
+    pub(crate) fn managed<R: Runtime>(manager: &impl Manager<R>) -> Self {
+        manager
+            .try_state::<BigIntPolicy>()
+            .map(|policy| *policy)
+            .unwrap_or_default()
+    }
+}
+
+/// The policy used to handle `invoke`, used by the handlers generated by the macros.
+pub fn invoke_policy<R: Runtime>(invoke: &Invoke<R>) -> BigIntPolicy {
+    BigIntPolicy::managed(&invoke.message.webview())
+}
+
+/// Wraps a value so it is serialized according to a [`BigIntPolicy`].
+#[derive(Clone)]
+pub(crate) struct BigIntSerialize<T>(pub T, pub BigIntPolicy);
+
+impl<T: Serialize> Serialize for BigIntSerialize<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let policy = self.1;
+        if policy.is_default() {
+            return self.0.serialize(serializer);
+        }
+
+        self.0.serialize(BigIntSerializer {
+            inner: serializer,
+            policy,
+        })
+    }
+}
+
+/// Serialize a response according to the [`BigIntPolicy`] of the router.
+fn serialize_response<T: Serialize>(
+    value: &T,
+    policy: BigIntPolicy,
+) -> Result<InvokeResponseBody, InvokeError> {
+    serde_json::to_string(&BigIntSerialize(value, policy))
+        .map(InvokeResponseBody::Json)
+        .map_err(InvokeError::from_error)
+}
+
+/// Serialize a value as a JavaScript literal, for the constants in the bindings.
+///
+/// With [`BigIntExport::Number`] integers outside of the safe integer range are an error,
+/// and with [`BigIntExport::BigInt`] they are written as `bigint` literals, e.g. `123n`.
+pub(crate) fn to_js_literal<T: Serialize>(
+    value: &T,
+    mode: BigIntExport,
+) -> serde_json::Result<String> {
+    let policy = match mode {
+        BigIntExport::Number => BigIntPolicy { mode, guard: true },
+        BigIntExport::BigInt => BigIntPolicy::default(),
+        BigIntExport::String => BigIntPolicy { mode, guard: false },
+    };
+    let mut out = Vec::new();
+    let formatter = LiteralFormatter {
+        pretty: serde_json::ser::PrettyFormatter::new(),
+        bigint: mode == BigIntExport::BigInt,
+        in_string: false,
+    };
+    BigIntSerialize(value, policy).serialize(&mut serde_json::Serializer::with_formatter(
+        &mut out, formatter,
+    ))?;
+    Ok(String::from_utf8(out).expect("serde_json writes valid UTF-8"))
+}
+
+/// Pretty prints JSON, 64 and 128-bit integers are suffixed with `n` when `bigint` is enabled.
+struct LiteralFormatter {
+    pretty: serde_json::ser::PrettyFormatter<'static>,
+    bigint: bool,
+    // Integer keys of maps are written inside of a string.
+    in_string: bool,
+}
+
+impl LiteralFormatter {
+    fn write_large<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: impl Display,
+    ) -> std::io::Result<()> {
+        match self.bigint && !self.in_string {
+            true => write!(writer, "{value}n"),
+            false => write!(writer, "{value}"),
+        }
+    }
+}
+
+impl serde_json::ser::Formatter for LiteralFormatter {
+    fn write_i64<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: i64,
+    ) -> std::io::Result<()> {
+        self.write_large(writer, value)
+    }
+
+    fn write_u64<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: u64,
+    ) -> std::io::Result<()> {
+        self.write_large(writer, value)
+    }
+
+    fn write_i128<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: i128,
+    ) -> std::io::Result<()> {
+        self.write_large(writer, value)
+    }
+
+    fn write_u128<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: u128,
+    ) -> std::io::Result<()> {
+        self.write_large(writer, value)
+    }
+
+    fn begin_string<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.in_string = true;
+        self.pretty.begin_string(writer)
+    }
+
+    fn end_string<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.in_string = false;
+        self.pretty.end_string(writer)
+    }
+
+    fn begin_array<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.pretty.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.pretty.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.pretty.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.pretty.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.pretty.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.pretty.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.pretty.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.pretty.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.pretty.end_object_value(writer)
+    }
+}
+
+struct Wrap<'a, T: ?Sized> {
+    value: &'a T,
+    policy: BigIntPolicy,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Wrap<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(BigIntSerializer {
+            inner: serializer,
+            policy: self.policy,
+        })
+    }
+}
+
+struct BigIntSerializer<S> {
+    inner: S,
+    policy: BigIntPolicy,
+}
+
+impl<S: Serializer> BigIntSerializer<S> {
+    fn wrap<'a, T: ?Sized>(&self, value: &'a T) -> Wrap<'a, T> {
+        Wrap {
+            value,
+            policy: self.policy,
+        }
+    }
+
+    fn serialize_large<T: Display + Copy + TryInto<i128>>(
+        self,
+        value: T,
+        as_number: impl FnOnce(S, T) -> Result<S::Ok, S::Error>,
+    ) -> Result<S::Ok, S::Error> {
+        match self.policy.mode {
+            BigIntExport::Number => {
+                let is_safe = value
+                    .try_into()
+                    .is_ok_and(|v: i128| (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&v));
+                if self.policy.guard && !is_safe {
+                    return Err(ser::Error::custom(format!(
+                        "integer `{value}` is outside of JavaScript's safe integer range"
+                    )));
+                }
+                as_number(self.inner, value)
+            }
+            BigIntExport::BigInt | BigIntExport::String => self.inner.collect_str(&value),
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for BigIntSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    fn serialize_bool(self, v: bool) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<S::Ok, S::Error> {
+        self.serialize_large(v, S::serialize_i64)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<S::Ok, S::Error> {
+        self.serialize_large(v, S::serialize_i128)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<S::Ok, S::Error> {
+        self.serialize_large(v, S::serialize_u64)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<S::Ok, S::Error> {
+        self.serialize_large(v, S::serialize_u128)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        Ok(Compound::new(self.inner.serialize_seq(len)?, self.policy))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        Ok(Compound::new(self.inner.serialize_tuple(len)?, self.policy))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        Ok(Compound::new(
+            self.inner.serialize_tuple_struct(name, len)?,
+            self.policy,
+        ))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        Ok(Compound::new(
+            self.inner
+                .serialize_tuple_variant(name, variant_index, variant, len)?,
+            self.policy,
+        ))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        Ok(Compound::new(self.inner.serialize_map(len)?, self.policy))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(Compound::new(
+            self.inner.serialize_struct(name, len)?,
+            self.policy,
+        ))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Ok(Compound::new(
+            self.inner
+                .serialize_struct_variant(name, variant_index, variant, len)?,
+            self.policy,
+        ))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct Compound<C> {
+    inner: C,
+    policy: BigIntPolicy,
+}
+
+impl<C> Compound<C> {
+    fn new(inner: C, policy: BigIntPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    fn wrap<'a, T: ?Sized>(&self, value: &'a T) -> Wrap<'a, T> {
+        Wrap {
+            value,
+            policy: self.policy,
+        }
+    }
+}
+
+impl<C: ser::SerializeSeq> ser::SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTuple> ser::SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    // Map keys are always strings in JSON, so they don't need to be converted.
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeStruct> ser::SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeStructVariant> ser::SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Deserialize an argument, 64 and 128-bit integers can be sent as JSON numbers or as strings.
+///
+/// Types that buffer their content, like internally tagged, untagged or flattened types, don't know
+/// the type of their fields and only accept JSON numbers.
+fn deserialize_arg<'a, T: DeserializeOwned, R: Runtime>(
+    item: impl Fn() -> CommandItem<'a, R>,
+) -> Result<T, InvokeError> {
+    let (name, key) = {
+        let item = item();
+        (item.name, item.key)
+    };
+
+    match Option::<Value>::from_command(item())? {
+        Some(value) => T::deserialize(LenientDeserializer(&value))
+            .map_err(|err| tauri::Error::InvalidArgs(name, key, err).into()),
+        // Let tauri handle missing arguments, this returns `None` for `Option<T>`'s.
+        None => T::from_command(item()),
+    }
+}
+
+/// Deserialize a JSON value according to the [`BigIntPolicy`], like the arguments of the procedures.
+pub(crate) fn deserialize_value<T: DeserializeOwned>(
+    value: &Value,
+    policy: BigIntPolicy,
+) -> Result<T, serde_json::Error> {
+    match policy.mode {
+        BigIntExport::Number => T::deserialize(value),
+        BigIntExport::BigInt | BigIntExport::String => T::deserialize(LenientDeserializer(value)),
+    }
+}
+
+/// A [`serde_json::Value`] deserializer that also accepts strings for 64 and 128-bit integers.
+struct LenientDeserializer<'a>(&'a Value);
+
+macro_rules! deserialize_int {
+    ($($fn:ident => $visit:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $fn<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.as_str() {
+                    Some(s) => match s.parse::<$ty>() {
+                        Ok(v) => visitor.$visit(v),
+                        Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &visitor)),
+                    },
+                    None => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> LenientDeserializer<'a> {
+    fn as_str(&self) -> Option<&'a str> {
+        self.0.as_str()
+    }
+}
+
+impl<'de> de::Deserializer<'de> for LenientDeserializer<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(*v),
+            Value::Number(n) => {
+                if let Some(v) = n.as_u64() {
+                    visitor.visit_u64(v)
+                } else if let Some(v) = n.as_i64() {
+                    visitor.visit_i64(v)
+                } else {
+                    visitor.visit_f64(n.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::String(s) => visitor.visit_borrowed_str(s),
+            Value::Array(values) => visitor.visit_seq(LenientSeq(values.iter())),
+            Value::Object(map) => visitor.visit_map(LenientMap {
+                iter: map.iter(),
+                value: None,
+            }),
+        }
+    }
+
+    // Smaller integers are never sent as strings, see `BigIntExport`.
+    deserialize_int! {
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::String(variant) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            Value::Object(map) if map.len() == 1 => {
+                let (variant, value) = map.iter().next().unwrap();
+                visitor.visit_enum(LenientEnum { variant, value })
+            }
+            value => value.deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 u8 u16 u32 f32 f64 char str string bytes byte_buf unit unit_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct LenientSeq<'a>(std::slice::Iter<'a, Value>);
+
+impl<'de> SeqAccess<'de> for LenientSeq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(LenientDeserializer(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct LenientMap<'a> {
+    iter: serde_json::map::Iter<'a>,
+    value: Option<&'a Value>,
+}
+
+impl<'de> MapAccess<'de> for LenientMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                // Keys are strings, so integer keys are parsed the same way as string encoded integers.
+                seed.deserialize(MapKeyDeserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(LenientDeserializer(value)),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct MapKeyDeserializer<'a>(&'a String);
+
+impl<'a> MapKeyDeserializer<'a> {
+    fn as_str(&self) -> Option<&'a str> {
+        Some(self.0.as_str())
+    }
+}
+
+impl<'de> de::Deserializer<'de> for MapKeyDeserializer<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    deserialize_int! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.as_str().into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct LenientEnum<'a> {
+    variant: &'a String,
+    value: &'a Value,
+}
+
+impl<'de> EnumAccess<'de> for LenientEnum<'de> {
+    type Error = serde_json::Error;
+    type Variant = LenientDeserializer<'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(self.variant.as_str().into_deserializer())?;
+        Ok((variant, LenientDeserializer(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for LenientDeserializer<'de> {
+    type Error = serde_json::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match self.0 {
+            Value::Null => Ok(()),
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::Map,
+                &"unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
+
+// Used by the code generated by `#[taurpc::procedures]`, these use autoref specialization to select
+// the correct implementation based on the type of the argument or response.
+// The responses are matched on `&&&ResponseRef(&res)`, serializable responses are preferred over other
+// `IpcResponse`s.
+
+pub struct DeserializeArgTag;
+
+pub trait DeserializeArgKind {
+    #[inline(always)]
+    fn taurpc_arg_kind(&self) -> DeserializeArgTag {
+        DeserializeArgTag
+    }
+}
+
+impl<T: DeserializeOwned> DeserializeArgKind for PhantomData<T> {}
+
+impl DeserializeArgTag {
+    #[inline(always)]
+    pub fn extract<'a, T: DeserializeOwned, R: Runtime>(
+        self,
+        item: impl Fn() -> CommandItem<'a, R>,
+        policy: BigIntPolicy,
+    ) -> Result<T, InvokeError> {
+        // Large integers are only sent as strings when the router uses a different policy.
+        match policy.mode {
+            BigIntExport::Number => T::from_command(item()),
+            BigIntExport::BigInt | BigIntExport::String => deserialize_arg(item),
+        }
+    }
+}
+
+pub struct CommandArgTag;
+
+pub trait CommandArgKind {
+    #[inline(always)]
+    fn taurpc_arg_kind(&self) -> CommandArgTag {
+        CommandArgTag
+    }
+}
+
+impl<T> CommandArgKind for &PhantomData<T> {}
+
+impl CommandArgTag {
+    #[inline(always)]
+    pub fn extract<'a, T: CommandArg<'a, R>, R: Runtime>(
+        self,
+        item: impl Fn() -> CommandItem<'a, R>,
+        _policy: BigIntPolicy,
+    ) -> Result<T, InvokeError> {
+        T::from_command(item())
+    }
+}
+
+pub struct ResponseRef<'a, T>(pub &'a T);
+
+pub struct ResultResponseTag;
+
+pub trait ResultResponseKind {
+    #[inline(always)]
+    fn taurpc_response_kind(&self) -> ResultResponseTag {
+        ResultResponseTag
+    }
+}
+
+impl<T: Serialize, E: Into<InvokeError>> ResultResponseKind for &&ResponseRef<'_, Result<T, E>> {}
+
+impl ResultResponseTag {
+    #[inline(always)]
+    pub fn respond<T: Serialize, E: Into<InvokeError>>(
+        self,
+        value: Result<T, E>,
+        policy: BigIntPolicy,
+    ) -> Result<InvokeResponseBody, InvokeError> {
+        value
+            .map_err(Into::into)
+            .and_then(|value| serialize_response(&value, policy))
+    }
+}
+
+pub struct ValueResponseTag;
+
+pub trait ValueResponseKind {
+    #[inline(always)]
+    fn taurpc_response_kind(&self) -> ValueResponseTag {
+        ValueResponseTag
+    }
+}
+
+impl<T: Serialize> ValueResponseKind for &&&ResponseRef<'_, T> {}
+
+impl ValueResponseTag {
+    #[inline(always)]
+    pub fn respond<T: Serialize>(
+        self,
+        value: T,
+        policy: BigIntPolicy,
+    ) -> Result<InvokeResponseBody, InvokeError> {
+        serialize_response(&value, policy)
+    }
+}
+
+pub struct RawResultResponseTag;
+
+pub trait RawResultResponseKind {
+    #[inline(always)]
+    fn taurpc_response_kind(&self) -> RawResultResponseTag {
+        RawResultResponseTag
+    }
+}
+
+impl<T: IpcResponse, E: Into<InvokeError>> RawResultResponseKind
+    for &ResponseRef<'_, Result<T, E>>
+{
+}
+
+impl RawResultResponseTag {
+    /// Responses that aren't serialized by TauRPC, e.g. `tauri::ipc::Response`, are sent as they are.
+    #[inline(always)]
+    pub fn respond<T: IpcResponse, E: Into<InvokeError>>(
+        self,
+        value: Result<T, E>,
+        _policy: BigIntPolicy,
+    ) -> Result<InvokeResponseBody, InvokeError> {
+        value
+            .map_err(Into::into)
+            .and_then(|value| value.body().map_err(Into::into))
+    }
+}
+
+pub struct RawResponseTag;
+
+pub trait RawResponseKind {
+    #[inline(always)]
+    fn taurpc_response_kind(&self) -> RawResponseTag {
+        RawResponseTag
+    }
+}
+
+impl<T: IpcResponse> RawResponseKind for ResponseRef<'_, T> {}
+
+impl RawResponseTag {
+    #[inline(always)]
+    pub fn respond<T: IpcResponse>(
+        self,
+        value: T,
+        _policy: BigIntPolicy,
+    ) -> Result<InvokeResponseBody, InvokeError> {
+        value.body().map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    const NUMBER: BigIntPolicy = BigIntPolicy {
+        mode: BigIntExport::Number,
+        guard: false,
+    };
+    const GUARD: BigIntPolicy = BigIntPolicy {
+        mode: BigIntExport::Number,
+        guard: true,
+    };
+    const BIGINT: BigIntPolicy = BigIntPolicy {
+        mode: BigIntExport::BigInt,
+        guard: false,
+    };
+    const STRING: BigIntPolicy = BigIntPolicy {
+        mode: BigIntExport::String,
+        guard: false,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: u64,
+        small: u32,
+        ids: Vec<i128>,
+        parent: Option<i64>,
+        counts: BTreeMap<u64, u128>,
+        kind: Kind,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Unit,
+        Newtype(u64),
+        Tuple(i64, u8),
+        Struct { size: usize },
+    }
+
+    fn item(kind: Kind) -> Item {
+        Item {
+            id: u64::MAX,
+            small: 7,
+            ids: vec![-1, u64::MAX.into()],
+            parent: Some(i64::MIN),
+            counts: BTreeMap::from([(1, 2)]),
+            kind,
+        }
+    }
+
+    fn serialize(value: impl Serialize, policy: BigIntPolicy) -> Result<Value, serde_json::Error> {
+        serde_json::to_string(&BigIntSerialize(value, policy))
+            .map(|json| serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn serialize_numbers() {
+        assert_eq!(
+            serde_json::to_string(&BigIntSerialize(u64::MAX, NUMBER)).unwrap(),
+            u64::MAX.to_string()
+        );
+        assert_eq!(
+            serde_json::to_string(&BigIntSerialize(item(Kind::Unit), NUMBER)).unwrap(),
+            serde_json::to_string(&item(Kind::Unit)).unwrap()
+        );
+    }
+
+    #[test]
+    fn js_literals() {
+        let value = (u64::MAX, 7u32, BTreeMap::from([(1u64, -2i64)]), "3");
+        assert_eq!(
+            to_js_literal(&value, BigIntExport::BigInt).unwrap(),
+            "[\n  18446744073709551615n,\n  7,\n  {\n    \"1\": -2n\n  },\n  \"3\"\n]"
+        );
+        assert_eq!(
+            to_js_literal(&value, BigIntExport::String).unwrap(),
+            "[\n  \"18446744073709551615\",\n  7,\n  {\n    \"1\": \"-2\"\n  },\n  \"3\"\n]"
+        );
+        assert!(to_js_literal(&value, BigIntExport::Number).is_err());
+        assert_eq!(to_js_literal(&1u64, BigIntExport::Number).unwrap(), "1");
+    }
+
+    #[test]
+    fn serialize_guard() {
+        let safe = MAX_SAFE_INTEGER as i64;
+        assert_eq!(serialize(safe, GUARD).unwrap(), json!(safe));
+        assert_eq!(serialize(-safe, GUARD).unwrap(), json!(-safe));
+        assert!(serialize(safe + 1, GUARD).is_err());
+        assert!(serialize(-safe - 1, GUARD).is_err());
+        assert!(serialize(u128::MAX, GUARD).is_err());
+        assert!(serialize(vec![Some(u64::MAX)], GUARD).is_err());
+        // Smaller integers are always safe.
+        assert_eq!(serialize(u32::MAX, GUARD).unwrap(), json!(u32::MAX));
+    }
+
+    #[test]
+    fn serialize_strings() {
+        for policy in [BIGINT, STRING] {
+            assert_eq!(
+                serialize(item(Kind::Tuple(-5, 1)), policy).unwrap(),
+                json!({
+                    "id": u64::MAX.to_string(),
+                    "small": 7,
+                    "ids": ["-1", u64::MAX.to_string()],
+                    "parent": i64::MIN.to_string(),
+                    "counts": { "1": "2" },
+                    "kind": { "Tuple": ["-5", 1] },
+                })
+            );
+        }
+
+        assert_eq!(
+            serialize(Kind::Newtype(1), BIGINT).unwrap(),
+            json!({ "Newtype": "1" })
+        );
+        assert_eq!(
+            serialize(Kind::Struct { size: 1 }, BIGINT).unwrap(),
+            json!({ "Struct": { "size": "1" } })
+        );
+    }
+
+    #[test]
+    fn deserialize_numbers() {
+        let value = serde_json::to_value(item(Kind::Unit)).unwrap();
+        assert_eq!(
+            deserialize_value::<Item>(&value, NUMBER).unwrap(),
+            item(Kind::Unit)
+        );
+        assert!(deserialize_value::<u64>(&json!("1"), NUMBER).is_err());
+    }
+
+    #[test]
+    fn deserialize_lenient() {
+        for kind in [
+            Kind::Unit,
+            Kind::Newtype(3),
+            Kind::Tuple(-3, 4),
+            Kind::Struct { size: 5 },
+        ] {
+            let expected = item(kind);
+            let numbers = serde_json::to_value(&expected).unwrap();
+            let strings = serialize(&expected, STRING).unwrap();
+            for value in [numbers, strings] {
+                assert_eq!(deserialize_value::<Item>(&value, BIGINT).unwrap(), expected);
+            }
+        }
+
+        // JSON numbers can't hold 128-bit integers outside of the 64-bit range.
+        assert_eq!(
+            deserialize_value::<u128>(&json!(u128::MAX.to_string()), STRING).unwrap(),
+            u128::MAX
+        );
+        assert_eq!(
+            deserialize_value::<i128>(&json!(i128::MIN.to_string()), STRING).unwrap(),
+            i128::MIN
+        );
+        assert_eq!(
+            deserialize_value::<Option<i64>>(&json!(null), STRING).unwrap(),
+            None
+        );
+        assert!(deserialize_value::<u64>(&json!("-1"), BIGINT).is_err());
+        assert!(deserialize_value::<u64>(&json!("one"), BIGINT).is_err());
+        // Smaller integers are never sent as strings.
+        assert!(deserialize_value::<u32>(&json!("1"), BIGINT).is_err());
+    }
+}
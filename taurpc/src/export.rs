@@ -1,33 +1,71 @@
-use heck::ToLowerCamelCase;
 use specta::{
     Format, Type, Types,
-    datatype::{DataType, Field, Function, NamedReferenceType, Primitive, Reference, Struct},
+    datatype::{
+        DataType, Field, Function, NamedDataType, NamedReferenceType, Primitive, Reference, Struct,
+    },
 };
 use specta_serde::Phase;
 use specta_typescript::{Error, Exporter as TsExporter, FrameworkExporter, Typescript, define};
 use specta_util::Remapper;
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
+
+use crate::{BigIntExport, PathDocs};
+use constants::ExportedConstant;
 use std::collections::BTreeMap;
 
+mod collisions;
+mod constants;
+mod frameworks;
+mod jsdoc;
+mod markdown;
+mod openrpc;
+mod optional_fields;
+mod package;
+mod procedure_types;
+mod remap;
+mod rename;
+mod runtime;
+mod rust;
+mod transforms;
+mod validators;
+
+pub use collisions::TypeCollisions;
+pub use constants::Constant;
+pub use frameworks::Framework;
+pub use optional_fields::OptionalFields;
+pub use package::PackageFormat;
+pub use remap::RuleSet;
+pub use runtime::ClientRuntime;
+pub use taurpc_rename::RenameRule;
+pub use transforms::Revive;
+pub use validators::Validator;
+
 const FRAMEWORK_HEADER: &str =
     "// This file has been generated by TauRPC. Do not edit this file manually.";
 
-static PACKAGE_JSON: &str = r#"
-{
-    "name": ".taurpc",
-    "types": "index.ts"
-}
+static BOILERPLATE_TS_EXPORT: &str = r#"
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(PROXY_ARGS)
 "#;
 
-static BOILERPLATE_TS_IMPORT: &str = r#"
-import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+static BOILERPLATE_TS_EXPORT_TYPES: &str = "export type { InferCommandOutput }\n";
+
+static BOILERPLATE_JS_EXPORT: &str = r#"
+/** @type {() => ReturnType<typeof createProxy<Router, RouterEvents>>} */
+export const createTauRPCProxy = () => createProxy(PROXY_ARGS)
 "#;
 
-static BOILERPLATE_TS_EXPORT: &str = r#"
-export const createTauRPCProxy = () => createProxy<Router>(ARGS_MAP)
-export type { InferCommandOutput }
+static BOILERPLATE_DTS_EXPORT: &str = r#"
+export declare const createTauRPCProxy: () => ReturnType<typeof createProxy<Router, RouterEvents>>
 "#;
 
+/// The kind of file that is generated, `Javascript` and `Declarations` are generated as a pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Typescript,
+    Javascript,
+    Declarations,
+}
+
 pub type ExportError = Error;
 
 /// A trait implemented by types that can generate TypeScript bindings
@@ -39,6 +77,31 @@ pub trait Exportable<R: tauri::Runtime> {
         BTreeMap<String, Vec<Function>>,
         BTreeMap<String, String>,
     );
+
+    /// Returns the names of the procedures marked as `#[taurpc(event)]`, keyed by path.
+    fn events(&self) -> BTreeMap<String, Vec<String>> {
+        BTreeMap::new()
+    }
+
+    /// Returns the names of the procedures that take their arguments as a single object, keyed by path.
+    fn object_args(&self) -> BTreeMap<String, Vec<String>> {
+        BTreeMap::new()
+    }
+
+    /// Returns the doc comments of the traits and arguments, keyed by path.
+    fn docs(&self) -> BTreeMap<String, PathDocs> {
+        BTreeMap::new()
+    }
+
+    /// Returns how 64 and 128-bit integers are sent to the frontend.
+    fn bigint(&self) -> BigIntExport {
+        BigIntExport::default()
+    }
+
+    /// Check that the procedures can be exported, an error fails the export.
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 /// A builder for exporting your TauRPC API to a TypeScript file.
@@ -61,6 +124,22 @@ pub trait Exportable<R: tauri::Runtime> {
 pub struct Exporter {
     ts_config: Typescript,
     specta_phases: bool,
+    validator: Option<Validator>,
+    frameworks: Vec<Framework>,
+    bigint: Option<BigIntExport>,
+    rules: Vec<(DataType, DataType)>,
+    revive: Vec<Revive>,
+    runtime: ClientRuntime,
+    package_format: PackageFormat,
+    rust_type_paths: Vec<(String, String)>,
+    openrpc_info: Option<(String, String)>,
+    deprecation_warnings: bool,
+    rename_all: Option<RenameRule>,
+    procedure_types: bool,
+    type_collisions: TypeCollisions,
+    optional_fields: OptionalFields,
+    types: Types,
+    constants: Vec<ExportedConstant>,
 }
 
 impl Exporter {
@@ -68,6 +147,22 @@ impl Exporter {
         Self {
             ts_config: Typescript::default(),
             specta_phases: true,
+            validator: None,
+            frameworks: Vec::new(),
+            bigint: None,
+            rules: Vec::new(),
+            revive: Vec::new(),
+            runtime: ClientRuntime::default(),
+            package_format: PackageFormat::default(),
+            rust_type_paths: Vec::new(),
+            openrpc_info: None,
+            deprecation_warnings: false,
+            rename_all: None,
+            procedure_types: false,
+            type_collisions: TypeCollisions::default(),
+            optional_fields: OptionalFields::default(),
+            types: Types::default(),
+            constants: Vec::new(),
         }
     }
 
@@ -89,57 +184,620 @@ impl Exporter {
         self
     }
 
+    /// Generate runtime validation schemas next to the TypeScript types.
+    ///
+    /// This exports a schema for every type (`User` -> `UserSchema`), and a `PROCEDURE_SCHEMAS`
+    /// object that contains the `input` (tuple of arguments) and `output` schemas of all procedures,
+    /// keyed by path and procedure name.
+    pub fn validators(mut self, validator: Validator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Generate bindings for a frontend framework, can be called multiple times to generate bindings for multiple frameworks.
+    ///
+    /// This also exports the `Procedures` and `Events` types, containing all commands and events keyed by their full path
+    /// (e.g. `"api.ui.trigger"`), and the `callProcedure` and `listenEvent` helpers which the framework bindings are built on.
+    pub fn framework(mut self, framework: Framework) -> Self {
+        if !self.frameworks.contains(&framework) {
+            self.frameworks.push(framework);
+            self.frameworks.sort();
+        }
+        self
+    }
+
+    /// Override the TypeScript type of 64 and 128-bit integers, by default this uses the mode
+    /// configured with [`Router::bigint`](crate::Router::bigint).
+    ///
+    /// This only changes the generated types, the mode of the router decides how the values are serialized.
+    pub fn bigint(mut self, mode: BigIntExport) -> Self {
+        self.bigint = Some(mode);
+        self
+    }
+
+    /// Export `T` as the given TypeScript type, wherever it is used.
+    ///
+    /// Make sure the TypeScript type matches how `T` is serialized, the runtime values are not changed.
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .remap::<UserId>("string & { readonly __brand: \"UserId\" }")
+    ///     .export(&router, "../src/lib/bindings.ts")
+    ///     .unwrap();
+    /// ```
+    pub fn remap<T: Type + ?Sized>(self, ts_type: impl Into<Cow<'static, str>>) -> Self {
+        self.remap_dt(remap::definition::<T>(), remap::ts_type(ts_type))
+    }
+
+    /// Replace every occurrence of the `from` datatype with `to`.
+    ///
+    /// Rules are applied in the order they are added, before the built-in bigint rules.
+    pub fn remap_dt(mut self, from: DataType, to: DataType) -> Self {
+        self.rules.push((from, to));
+        self
+    }
+
+    /// Add a set of built-in remapping rules, see [`RuleSet`].
+    pub fn rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rules.extend(rule_set.rules());
+        self
+    }
+
+    /// Convert values that can't be represented in JSON on the frontend, see [`Revive`].
+    /// Can be called multiple times to revive multiple kinds of values.
+    ///
+    /// The exported types are changed to match, and the generated client converts the values
+    /// in the outputs of commands and events, and converts them back in the arguments.
+    /// These rules take priority over the rules added with [`Exporter::remap`].
+    pub fn revive(mut self, revive: Revive) -> Self {
+        if !self.revive.contains(&revive) {
+            self.revive.push(revive);
+            self.revive.sort();
+        }
+        self
+    }
+
+    /// Configure where the generated bindings get the frontend runtime from, see [`ClientRuntime`].
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .runtime(taurpc::ClientRuntime::Inline)
+    ///     .export(&router, "../src/lib/bindings.ts")
+    ///     .unwrap();
+    /// ```
+    pub fn runtime(mut self, runtime: ClientRuntime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Log a warning in the console when a procedure marked as `#[deprecated]` is called, once per procedure.
+    pub fn deprecation_warnings(mut self, enabled: bool) -> Self {
+        self.deprecation_warnings = enabled;
+        self
+    }
+
+    /// Choose how types with the same name from different Rust modules are exported, see [`TypeCollisions`].
+    ///
+    /// By default the export fails with an error that lists the Rust paths of the colliding types.
+    pub fn type_collisions(mut self, collisions: TypeCollisions) -> Self {
+        self.type_collisions = collisions;
+        self
+    }
+
+    /// Choose how fields of type `Option<T>` are rendered, see [`OptionalFields`].
+    ///
+    /// By default they are rendered as `field: T | null`. The other styles only apply to the fields that skip `None`
+    /// when they are serialized, e.g. with `#[taurpc::ipc_type(skip_none)]`, the other fields keep `T | null`.
+    pub fn optional_fields(mut self, style: OptionalFields) -> Self {
+        self.optional_fields = style;
+        self
+    }
+
+    /// Choose the naming convention of the arguments in the bindings, see [`RenameRule`].
+    ///
+    /// By default the arguments that are renamed with `#[taurpc(rename)]` or `rename_all` on the trait keep
+    /// their name, the other arguments are converted to camelCase.
+    ///
+    /// Unlike `#[taurpc::procedures(rename_all = "...")]`, this only renames the arguments in the bindings, the
+    /// keys sent to the backend don't change. Procedures and events keep their names, as the backend matches on
+    /// them, rename them with `rename_all` on the trait instead.
+    pub fn rename_all(mut self, rule: RenameRule) -> Self {
+        self.rename_all = Some(rule);
+        self
+    }
+
+    /// Export the type `T`, even if it isn't used by any procedure.
+    /// This is useful for types that are only used on the frontend, e.g. for values stored in `localStorage`.
+    pub fn with_type<T: Type>(mut self) -> Self {
+        self.types.register_mut::<T>();
+        self
+    }
+
+    /// Export a Rust constant marked with `#[taurpc::constant]` as a typed `export const`,
+    /// its value is serialized to JSON.
+    ///
+    /// ```rust,ignore
+    /// /// The maximum number of users.
+    /// #[taurpc::constant]
+    /// const MAX_USERS: u32 = 100;
+    ///
+    /// taurpc::Exporter::new()
+    ///     .constant::<MAX_USERS>()
+    ///     .export(&router, "../src/lib/bindings.ts")
+    ///     .unwrap();
+    /// ```
+    pub fn constant<C: Constant>(mut self) -> Self {
+        let constant = ExportedConstant::new::<C>(&mut self.types);
+        self.constants.push(constant);
+        self
+    }
+
+    /// Export a named type for the arguments and output of every procedure, e.g. `Api_TestIoInput` and `Api_TestIoOutput`.
+    /// This also exports the `ProcedurePath` union with the paths of all procedures, and the `AllEvents` union
+    /// with the payloads of all events tagged by their path.
+    pub fn procedure_types(mut self, enabled: bool) -> Self {
+        self.procedure_types = enabled;
+        self
+    }
+
+    /// Choose the files generated by [`Exporter::export_package`], see [`PackageFormat`].
+    pub fn package_format(mut self, format: PackageFormat) -> Self {
+        self.package_format = format;
+        self
+    }
+
+    /// Replace the module path of the types used by [`Exporter::export_rust`].
+    ///
+    /// The types are referenced by their module path in the backend crate (`::my_app::models::User`),
+    /// use this when they are shared with the frontend through another crate.
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .rust_type_path("my_app::models", "::shared::models")
+    ///     .export_rust(&router, "../src-ui/src/bindings.rs")
+    ///     .unwrap();
+    /// ```
+    pub fn rust_type_path(
+        mut self,
+        module_path: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Self {
+        self.rust_type_paths.push((module_path.into(), path.into()));
+        self
+    }
+
+    /// Set the title and version of the API in the document generated by [`Exporter::export_openrpc`].
+    pub fn openrpc_info(mut self, title: impl Into<String>, version: impl Into<String>) -> Self {
+        self.openrpc_info = Some((title.into(), version.into()));
+        self
+    }
+
     /// Exports the generated TypeScript bindings to the specified file path.
     pub fn export<R: tauri::Runtime>(
         self,
         exportable: &impl Exportable<R>,
-        path: impl AsRef<std::path::Path>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        let (exporter, types, format) = self.exporter(exportable, Output::Typescript)?;
+        exporter.export_to(path, &types, format)?;
+
+        if path.ends_with("node_modules/.taurpc/index.ts") {
+            let dir = path
+                .parent()
+                .ok_or(Error::framework("", "Failed to create 'package.json' path"))?;
+            package::write_package_json(dir, PackageFormat::Typescript)?;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the bindings as JavaScript to the specified file path, for frontends that don't use TypeScript.
+    ///
+    /// The types are exported as JSDoc `@typedef`s, and to a declaration file next to it
+    /// (`bindings.js` -> `bindings.d.ts`). This doesn't support [`ClientRuntime::Inline`].
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .export_js(&router, "../src/lib/bindings.js")
+    ///     .unwrap();
+    /// ```
+    pub fn export_js<R: tauri::Runtime>(
+        self,
+        exportable: &impl Exportable<R>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        let declarations = path.with_extension(match path.extension() {
+            Some(extension) if extension == "mjs" => "d.mts",
+            _ => "d.ts",
+        });
+
+        self.write_js(exportable, path, &declarations)
+    }
+
+    /// Exports the bindings as a `.taurpc` package in the `node_modules` folder of the frontend project,
+    /// so they can be imported with `import { createTauRPCProxy } from '.taurpc'`.
+    ///
+    /// ```rust,ignore
+    /// #[cfg(debug_assertions)]
+    /// taurpc::Exporter::new()
+    ///     .export_package(&router, "..")
+    ///     .unwrap();
+    /// ```
+    pub fn export_package<R: tauri::Runtime>(
+        self,
+        exportable: &impl Exportable<R>,
+        project_root: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let dir = project_root.as_ref().join("node_modules").join(".taurpc");
+
+        match self.package_format {
+            PackageFormat::Typescript => {
+                let (exporter, types, format) = self.exporter(exportable, Output::Typescript)?;
+                exporter.export_to(dir.join("index.ts"), &types, format)?;
+            }
+            PackageFormat::Javascript => {
+                self.write_js(exportable, &dir.join("index.js"), &dir.join("index.d.ts"))?;
+            }
+        }
+
+        package::write_package_json(&dir, self.package_format)
+    }
+
+    /// Exports a Rust client to the specified file path, for WASM frontends (Leptos, Yew, Dioxus, ...).
+    ///
+    /// The procedures are exported as async functions in a module per path, and events as `on_{name}` listeners.
+    /// The types aren't re-emitted, they should be shared with the frontend, see [`Exporter::rust_type_path`].
+    /// The client deserializes large integers as numbers, so the export fails when the router uses a different
+    /// [`BigIntExport`].
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .export_rust(&router, "../src-ui/src/bindings.rs")
+    ///     .unwrap();
+    /// ```
+    pub fn export_rust<R: tauri::Runtime>(
+        self,
+        exportable: &impl Exportable<R>,
+        path: impl AsRef<Path>,
     ) -> Result<(), Error> {
+        exportable.validate()?;
+        if exportable.bigint() != BigIntExport::Number {
+            return Err(Error::framework(
+                "failed to generate the Rust client",
+                "large integers are only supported with `BigIntExport::Number`",
+            ));
+        }
         let (types, functions, args_map) = exportable.generate_types();
-        let format = SpectaFormat::new(self.specta_phases);
+        let client = rust::generate_client(
+            &types,
+            &functions,
+            &args_map,
+            &exportable.events(),
+            &self.rust_type_paths,
+        );
+
+        package::write_file(path.as_ref(), client)
+    }
+
+    /// Exports an [OpenRPC](https://open-rpc.org) document describing the API to the specified file path.
+    ///
+    /// Every procedure is described as a method named after its full path, with JSON Schemas for the
+    /// arguments, the result and the error. Events are described as notifications.
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .openrpc_info("My app", env!("CARGO_PKG_VERSION"))
+    ///     .export_openrpc(&router, "../openrpc.json")
+    ///     .unwrap();
+    /// ```
+    pub fn export_openrpc<R: tauri::Runtime>(
+        self,
+        exportable: &impl Exportable<R>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let (_, _, args_map) = exportable.generate_types();
+        let events = exportable.events();
+        let info = self
+            .openrpc_info
+            .clone()
+            .unwrap_or_else(|| ("TauRPC".to_string(), "0.0.0".to_string()));
+
+        let document =
+            self.render_document(exportable, false, move |exporter, functions, format| {
+                openrpc::generate_document(&info, functions, &args_map, &events, exporter, format)
+                    .map_err(|err| Error::framework("failed to generate the OpenRPC document", err))
+            })?;
+
+        package::write_file(path.as_ref(), document)
+    }
+
+    /// Exports a Markdown reference of the API to the specified file path.
+    ///
+    /// The procedures and events are grouped by path, with their signatures and links to the type definitions.
+    ///
+    /// ```rust,ignore
+    /// taurpc::Exporter::new()
+    ///     .export_markdown(&router, "../docs/api.md")
+    ///     .unwrap();
+    /// ```
+    pub fn export_markdown<R: tauri::Runtime>(
+        self,
+        exportable: &impl Exportable<R>,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let events = exportable.events();
+        let object_args = exportable.object_args();
+        let reference =
+            self.render_document(exportable, true, move |exporter, functions, format| {
+                markdown::generate_reference(functions, &events, &object_args, exporter, format)
+                    .map_err(|err| Error::framework("failed to generate the API reference", err))
+            })?;
+
+        package::write_file(path.as_ref(), reference)
+    }
+
+    fn write_js<R: tauri::Runtime>(
+        &self,
+        exportable: &impl Exportable<R>,
+        path: &Path,
+        declarations: &Path,
+    ) -> Result<(), Error> {
+        for (output, path) in [
+            (Output::Javascript, path),
+            (Output::Declarations, declarations),
+        ] {
+            let (exporter, types, format) = self.exporter(exportable, output)?;
+            package::write_file(path, exporter.export(&types, format)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// The `specta_typescript` config and the format shared by all outputs,
+    /// `revive` is disabled for outputs that describe the values sent over IPC.
+    fn config<R: tauri::Runtime>(
+        &self,
+        exportable: &impl Exportable<R>,
+        revive: bool,
+    ) -> (Typescript, SpectaFormat) {
+        let bigint = self.bigint.unwrap_or_else(|| exportable.bigint());
+        let revive: &[Revive] = if revive { &self.revive } else { &[] };
+        let revive_maps = revive.contains(&Revive::Maps);
+        let rules = revive
+            .iter()
+            .flat_map(Revive::rules)
+            .chain(self.rules.iter().cloned())
+            .collect();
+        let format = SpectaFormat::new(
+            self.specta_phases,
+            bigint,
+            rules,
+            revive_maps,
+            self.optional_fields,
+        );
+
+        let mut ts_config = self.ts_config.clone();
+        if revive_maps {
+            ts_config = ts_config.branded_type_impl(|exporter, branded| {
+                let render = |dt: &DataType| match dt {
+                    DataType::Reference(r) => exporter.reference(r),
+                    dt => exporter.inline(dt),
+                };
+
+                Ok(match transforms::revived_map(branded) {
+                    Some((key, value)) => format!("Map<{}, {}>", render(key)?, render(value)?),
+                    // The default rendering of branded types.
+                    None => format!(
+                        "{} & {{ readonly __brand: {} }}",
+                        render(branded.ty())?,
+                        serde_json::to_string(branded.brand()).unwrap()
+                    ),
+                }
+                .into())
+            });
+        }
+
+        (ts_config, format)
+    }
+
+    /// Render a document that isn't TypeScript from the formatted types, e.g. the OpenRPC document.
+    fn render_document<R: tauri::Runtime>(
+        &self,
+        exportable: &impl Exportable<R>,
+        revive: bool,
+        render: impl Fn(
+            &FrameworkExporter,
+            &BTreeMap<String, Vec<Function>>,
+            &SpectaFormat,
+        ) -> Result<String, Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Result<String, Error> {
+        exportable.validate()?;
+        let (mut types, mut functions, args_map) = exportable.generate_types();
+        types.extend(&self.types);
+        collisions::resolve_collisions(&mut types, self.type_collisions)?;
+        rename::rename_args(
+            &mut functions,
+            &mut BTreeMap::new(),
+            &args_map,
+            self.rename_all,
+        );
+        let (ts_config, format) = self.config(exportable, revive);
+        let format_clone = format.clone();
+
+        let exporter = TsExporter::from(ts_config.header(""))
+            .framework_prelude("")
+            .framework_runtime(move |mut exporter| {
+                // The types are always rendered, otherwise they are appended to the output.
+                exporter.render_types()?;
+                Ok(render(&exporter, &functions, &format_clone)?.into())
+            });
+
+        // The document is surrounded by the newlines added by `specta_typescript`.
+        let out = exporter.export(&types, format)?;
+        Ok(out.trim_matches('\n').to_string() + "\n")
+    }
+
+    /// Configure the `specta_typescript` exporter that renders the bindings for the given output.
+    fn exporter<R: tauri::Runtime>(
+        &self,
+        exportable: &impl Exportable<R>,
+        output: Output,
+    ) -> Result<(TsExporter, Types, SpectaFormat), Error> {
+        exportable.validate()?;
+        let (mut types, mut functions, args_map) = exportable.generate_types();
+        types.extend(&self.types);
+        collisions::resolve_collisions(&mut types, self.type_collisions)?;
+        let mut docs = exportable.docs();
+        rename::rename_args(&mut functions, &mut docs, &args_map, self.rename_all);
+        let (ts_config, format) = self.config(exportable, true);
         let format_clone = format.clone();
+        let validator = self.validator;
+        let frameworks = self.frameworks.clone();
+        let events = exportable.events();
+        let object_args = exportable.object_args();
+        let deprecation_warnings = self.deprecation_warnings;
+        let runtime = self.runtime.clone();
+        let procedure_types = self.procedure_types;
+        let constants = self.constants.clone();
 
-        TsExporter::from(self.ts_config)
+        let exporter = TsExporter::from(ts_config)
             .framework_prelude(FRAMEWORK_HEADER)
             .framework_runtime(move |mut exporter| {
                 let mut out = String::new();
 
-                out.push_str(&exporter.render_types()?);
-                out.push_str(BOILERPLATE_TS_IMPORT);
+                // The types are always rendered, otherwise they are appended to the output.
+                let types = exporter.render_types()?;
+                match output {
+                    Output::Javascript => out.push_str(&jsdoc::generate_typedefs(&exporter)?),
+                    _ => out.push_str(&types),
+                }
+                out.push_str(&runtime.generate(output)?);
 
-                out.push_str(r#"const ARGS_MAP = "#);
-                out.push_str(
-                    &serde_json::to_string_pretty(&args_map)
-                        .map_err(|err| Error::framework("error stringify argument map", err))?,
+                let transforms = transforms::generate_transforms(
+                    &functions,
+                    &events,
+                    exporter.types,
+                    &format_clone,
                 );
-                out.push_str(";\n\n");
+                let deprecations = deprecation_warnings
+                    .then(|| generate_deprecations(&functions, &events))
+                    .flatten();
+                let object_args_fields = generate_object_args(&functions, &object_args);
+                if output != Output::Declarations {
+                    out.push_str(r#"const ARGS_MAP = "#);
+                    out.push_str(
+                        &serde_json::to_string_pretty(&args_map)
+                            .map_err(|err| Error::framework("error stringify argument map", err))?,
+                    );
+                    out.push_str(";\n\n");
+
+                    if let Some(transforms) = &transforms {
+                        out.push_str(transforms);
+                        out.push('\n');
+                    }
+                    if let Some(deprecations) = &deprecations {
+                        out.push_str(deprecations);
+                        out.push('\n');
+                    }
+                    if let Some(object_args_fields) = &object_args_fields {
+                        out.push_str(object_args_fields);
+                        out.push('\n');
+                    }
+                }
 
                 out.push_str(
-                    &generate_functions_router(&functions, &exporter, &format_clone)
-                        .map_err(|err| Error::framework("failed to generate router type", err))?,
+                    &generate_functions_router(
+                        &functions,
+                        &events,
+                        &object_args,
+                        &docs,
+                        &exporter,
+                        &format_clone,
+                        output,
+                    )
+                    .map_err(|err| Error::framework("failed to generate router type", err))?,
                 );
-                out.push_str(BOILERPLATE_TS_EXPORT);
+                if procedure_types {
+                    out.push_str(
+                        &procedure_types::generate_procedure_types(
+                            &functions,
+                            &events,
+                            &object_args,
+                            &exporter,
+                            &format_clone,
+                            output,
+                        )
+                        .map_err(|err| {
+                            Error::framework("failed to generate procedure types", err)
+                        })?,
+                    );
+                }
+                if !constants.is_empty() {
+                    out.push('\n');
+                    out.push_str(&constants::generate_constants(
+                        &constants,
+                        &exporter,
+                        &format_clone,
+                        output,
+                    )?);
+                }
+                // Trailing arguments that aren't used are omitted.
+                let mut proxy_args = vec![
+                    Some("ARGS_MAP"),
+                    transforms.is_some().then_some("TRANSFORMS"),
+                    deprecations.is_some().then_some("DEPRECATIONS"),
+                    object_args_fields.is_some().then_some("OBJECT_ARGS"),
+                ];
+                while proxy_args.last() == Some(&None) {
+                    proxy_args.pop();
+                }
+                let proxy_args = proxy_args
+                    .into_iter()
+                    .map(|arg| arg.unwrap_or("undefined"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                out.push_str(&match output {
+                    Output::Typescript => BOILERPLATE_TS_EXPORT.replace("PROXY_ARGS", &proxy_args),
+                    Output::Javascript => BOILERPLATE_JS_EXPORT.replace("PROXY_ARGS", &proxy_args),
+                    Output::Declarations => BOILERPLATE_DTS_EXPORT.to_string(),
+                });
+                if output != Output::Javascript && runtime.reexports_types() {
+                    out.push_str(BOILERPLATE_TS_EXPORT_TYPES);
+                }
 
-                Ok(out.into())
-            })
-            .export_to(path.as_ref(), &types, format)?;
-
-        if path
-            .as_ref()
-            .to_string_lossy()
-            .ends_with("node_modules\\.taurpc\\index.ts")
-        {
-            let package_json_path = path
-                .as_ref()
-                .parent()
-                .ok_or(Error::framework("", "Failed to create 'package.json' path"))?
-                .join("package.json");
+                if !frameworks.is_empty() {
+                    out.push_str(&frameworks::generate_frameworks(
+                        &frameworks,
+                        &functions,
+                        &events,
+                        output,
+                    ));
+                }
 
-            std::fs::write(package_json_path, PACKAGE_JSON)
-                .map_err(|err| Error::framework("Failed to create 'package.json' file", err))?
-        }
+                if let Some(validator) = validator {
+                    out.push_str(
+                        &validators::generate_validators(
+                            validator,
+                            &functions,
+                            &events,
+                            &object_args,
+                            &exporter,
+                            &format_clone,
+                            output,
+                        )
+                        .map_err(|err| Error::framework("failed to generate validators", err))?,
+                    );
+                }
 
-        Ok(())
+                Ok(out.into())
+            });
+
+        Ok((exporter, types, format))
     }
 }
 
@@ -147,44 +805,86 @@ impl Exporter {
 #[derive(Debug, Clone)]
 struct SpectaFormat {
     specta_phases_enabled: bool,
+    bigint: BigIntExport,
     remapper: Remapper,
+    revive_maps: bool,
+    optional_fields: OptionalFields,
     // semantic_types:
 }
 
 impl SpectaFormat {
-    fn new(specta_phases_enabled: bool) -> Self {
-        let mut remapper = Remapper::new();
+    fn new(
+        specta_phases_enabled: bool,
+        bigint: BigIntExport,
+        rules: Vec<(DataType, DataType)>,
+        revive_maps: bool,
+        optional_fields: OptionalFields,
+    ) -> Self {
+        let mut remapper = rules
+            .into_iter()
+            .fold(Remapper::new(), |remapper, (from, to)| {
+                remapper.rule(from, to)
+            });
 
-        // Always cast bigints to number for TauRPC
+        // `usize` and `isize` are serialized as 64-bit integers by serde, so they follow the bigint mode as well.
         let number = <specta_typescript::Number as Type>::definition(&mut Types::default());
+        let big_int = <specta_typescript::BigInt as Type>::definition(&mut Types::default());
+        let large = match bigint {
+            BigIntExport::Number => number,
+            BigIntExport::BigInt => big_int.clone(),
+            BigIntExport::String => DataType::Reference(define("string")),
+        };
         remapper = remapper
-            .rule(DataType::Primitive(Primitive::usize), number.clone())
-            .rule(DataType::Primitive(Primitive::isize), number.clone())
-            .rule(DataType::Primitive(Primitive::u64), number.clone())
-            .rule(DataType::Primitive(Primitive::i64), number.clone())
-            .rule(DataType::Primitive(Primitive::u128), number.clone())
-            .rule(DataType::Primitive(Primitive::i128), number.clone())
-            .rule(
-                <specta_typescript::BigInt as Type>::definition(&mut Types::default()),
-                number,
-            );
+            .rule(DataType::Primitive(Primitive::usize), large.clone())
+            .rule(DataType::Primitive(Primitive::isize), large.clone())
+            .rule(DataType::Primitive(Primitive::u64), large.clone())
+            .rule(DataType::Primitive(Primitive::i64), large.clone())
+            .rule(DataType::Primitive(Primitive::u128), large.clone())
+            .rule(DataType::Primitive(Primitive::i128), large.clone());
+        if large != big_int {
+            remapper = remapper.rule(big_int, large);
+        }
 
         Self {
             specta_phases_enabled,
+            bigint,
             remapper,
+            revive_maps,
+            optional_fields,
+        }
+    }
+
+    /// Apply the remapping rules, replace maps when they are revived and rewrite the optional fields.
+    fn remap(&self, dt: DataType) -> DataType {
+        let mut dt = self.remapper.remap_dt(dt);
+        if self.revive_maps {
+            transforms::revive_maps(&mut dt);
         }
+        self.optional_fields.apply(&mut dt);
+        dt
     }
 }
 
 impl Format for SpectaFormat {
     fn map_types(&'_ self, types: &Types) -> Result<Cow<'_, Types>, specta::FormatError> {
+        let marked = types
+            .clone()
+            .map(|ndt| self.optional_fields.mark_skipped(ndt));
         let types = if self.specta_phases_enabled {
-            specta_serde::PhasesFormat.map_types(types)
+            specta_serde::PhasesFormat.map_types(&marked)
         } else {
-            specta_serde::Format.map_types(types)
+            specta_serde::Format.map_types(&marked)
         }?;
 
-        Ok(Cow::Owned(self.remapper.remap_types(types.into_owned())))
+        Ok(Cow::Owned(types.into_owned().map(|mut ndt| {
+            ndt.generics.to_mut().iter_mut().for_each(|generic| {
+                if let Some(dt) = generic.default.take() {
+                    generic.default = Some(self.remap(dt));
+                }
+            });
+            ndt.ty = ndt.ty.take().map(|dt| self.remap(dt));
+            ndt
+        })))
     }
 
     fn map_type(
@@ -198,68 +898,292 @@ impl Format for SpectaFormat {
             specta_serde::Format.map_type(types, dt)?
         };
 
-        Ok(Cow::Owned(self.remapper.remap_dt(dt.into_owned())))
+        Ok(Cow::Owned(self.remap(dt.into_owned())))
     }
 }
 
 /// Export the generated TS types with the code necessary for generating the client proxy.
 fn generate_functions_router(
     functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    object_args: &BTreeMap<String, Vec<String>>,
+    docs: &BTreeMap<String, PathDocs>,
     exporter: &FrameworkExporter,
     format: &SpectaFormat,
+    output: Output,
 ) -> Result<String, Error> {
-    let mut router = Struct::named();
+    // Events can only be listened to, so they are exported in `RouterEvents` instead of `Router`.
+    let mut router = Vec::new();
+    let mut router_events = Vec::new();
 
     for (path, path_functions) in functions {
         let mut function_names_and_funcs: Vec<_> =
             path_functions.iter().map(|f| (f.name(), f)).collect();
         function_names_and_funcs.sort_by(|a, b| a.0.cmp(b.0));
 
-        let mut path_router = Struct::named();
+        let path_docs = docs.get(path);
+        let path_events = events.get(path);
+        let mut path_router = Vec::new();
+        let mut path_router_events = Vec::new();
         for (_, function) in function_names_and_funcs {
-            let (name, field) = generate_function_field(function, exporter, format)?;
-            path_router = path_router.field(name, field);
+            let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+            let is_event = path_events.is_some_and(|events| events.iter().any(|e| e == name));
+            let field = generate_function_field(
+                function,
+                takes_object_args(object_args, path, function),
+                is_event,
+                path_docs,
+                exporter,
+                format,
+            )?;
+            match is_event {
+                true => path_router_events.push(field),
+                false => path_router.push(field),
+            }
         }
 
-        router = router.field(path.clone(), Field::new(path_router.build()));
+        // Empty paths are left out, the proxy can't tell them apart from an index signature.
+        for (router, path_router) in [
+            (&mut router, path_router),
+            (&mut router_events, path_router_events),
+        ] {
+            if path_router.is_empty() {
+                continue;
+            }
+            let mut field = Field::new(router_type(path_router));
+            if let Some(path_docs) = path_docs {
+                field.docs = path_docs.docs.clone().into();
+            }
+            router.push((path.clone(), field));
+        }
+    }
+
+    let mut out = String::new();
+    for (name, router) in [("Router", router), ("RouterEvents", router_events)] {
+        let router = NamedDataType::new(name, &mut Types::default(), |_, ndt| {
+            ndt.ty = Some(router_type(router));
+        });
+        out.push_str(&match output {
+            Output::Javascript => jsdoc::typedef(&router, exporter)?,
+            _ => exporter.export(std::iter::once(&router), "")?,
+        });
     }
+    Ok(out)
+}
 
-    let router_type = exporter.inline(&router.build())?;
-    Ok(format!("export type Router = {router_type};\n"))
+/// Reference the type of a procedure, `Router["path"]["name"]` or `RouterEvents["path"]["name"]` for events.
+fn router_type_ref(events: &BTreeMap<String, Vec<String>>, path: &str, name: &str) -> String {
+    let is_event = events
+        .get(path)
+        .is_some_and(|events| events.iter().any(|event| event == name));
+    let router = if is_event { "RouterEvents" } else { "Router" };
+    format!("{router}[\"{path}\"][\"{name}\"]")
+}
+
+/// An object type with the fields, specta would export an empty struct as `Record<string, never>`.
+fn router_type(fields: Vec<(String, Field)>) -> DataType {
+    if fields.is_empty() {
+        return DataType::Reference(define("Record<never, never>"));
+    }
+    fields
+        .into_iter()
+        .fold(Struct::named(), |router, (name, field)| {
+            router.field(name, field)
+        })
+        .build()
 }
 
 fn generate_function_field(
     function: &Function,
+    object_args: bool,
+    is_event: bool,
+    docs: Option<&PathDocs>,
     exporter: &FrameworkExporter,
     format: &SpectaFormat,
 ) -> Result<(String, Field), Error> {
+    let args = render_args(function, object_args, is_event, exporter, format)?.join(", ");
+    // Events are typed as the listener that receives their payload.
+    let return_ty = match is_event {
+        true => "void".to_string(),
+        false => format!("Promise<{}>", render_output(function, exporter, format)?),
+    };
+
+    let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+
+    let mut field = Field::new(DataType::Reference(define(format!(
+        "({args}) => {return_ty}"
+    ))));
+    field.docs = function_docs(function, docs.and_then(|docs| docs.args.get(name))).into();
+    field.deprecated = function.deprecated.clone();
+    Ok((name.to_string(), field))
+}
+
+/// Render the arguments of the procedure as `name: Type`, procedures with `#[taurpc(args = "object")]`
+/// take a single `args` object.
+fn render_args(
+    function: &Function,
+    object_args: bool,
+    is_event: bool,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+) -> Result<Vec<String>, Error> {
     let args = function
         .args()
         .iter()
-        .map(|(name, typ)| {
-            render_reference_dt_for_phase(typ, Phase::Deserialize, exporter, format)
-                .map(|ty| format!("{}: {ty}", name.to_lower_camel_case()))
+        .enumerate()
+        .map(|(idx, (name, typ))| {
+            let optional = if is_optional_arg(function, idx, object_args) {
+                "?"
+            } else {
+                ""
+            };
+            render_reference_dt_for_phase(typ, args_phase(is_event), exporter, format)
+                .map(|ty| format!("{name}{optional}: {ty}"))
         })
-        .collect::<Result<Vec<_>, _>>()?
-        .join(", ");
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let return_ty = if let Some(result) = function.result() {
-        if let Some((dt_ok, _dt_err)) = extract_std_result(result, exporter.types) {
-            render_reference_dt_for_phase(dt_ok, Phase::Serialize, exporter, format)?
-        } else {
-            render_reference_dt_for_phase(result, Phase::Serialize, exporter, format)?
+    Ok(match object_args && !args.is_empty() {
+        true => vec![format!("args: {{ {} }}", args.join("; "))],
+        false => args,
+    })
+}
+
+/// The phase of the arguments, events are sent by the backend so their arguments are serialized.
+fn args_phase(is_event: bool) -> Phase {
+    match is_event {
+        true => Phase::Serialize,
+        false => Phase::Deserialize,
+    }
+}
+
+/// Render the type that the procedure resolves with, the error of a `Result` is thrown instead.
+fn render_output(
+    function: &Function,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+) -> Result<String, Error> {
+    match function.result() {
+        Some(result) => {
+            let ok = extract_std_result(result, exporter.types)
+                .map(|(ok, _)| ok)
+                .unwrap_or(result);
+            render_reference_dt_for_phase(ok, Phase::Serialize, exporter, format)
         }
-    } else {
-        "void".to_string()
-    };
+        None => Ok("void".to_string()),
+    }
+}
+
+/// Render the `DEPRECATIONS` object, with the notes of the deprecated procedures keyed by path.
+fn generate_deprecations(
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+) -> Option<String> {
+    let mut deprecations = BTreeMap::new();
+    for (path, path_functions) in functions {
+        for function in path_functions {
+            let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+
+            if let Some(deprecated) = &function.deprecated
+                && !is_event
+            {
+                deprecations
+                    .entry(path.clone())
+                    .or_insert_with(BTreeMap::new)
+                    .insert(name, deprecated.note.clone());
+            }
+        }
+    }
+
+    if deprecations.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "const DEPRECATIONS = {};\n",
+        serde_json::to_string_pretty(&deprecations).unwrap()
+    ))
+}
+
+/// Render the `OBJECT_ARGS` object, with the fields of the procedures that take their arguments as an object.
+fn generate_object_args(
+    functions: &BTreeMap<String, Vec<Function>>,
+    object_args: &BTreeMap<String, Vec<String>>,
+) -> Option<String> {
+    let mut fields = BTreeMap::new();
+    for (path, path_functions) in functions {
+        for function in path_functions {
+            if !takes_object_args(object_args, path, function) || function.args().is_empty() {
+                continue;
+            }
+
+            let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+            let names = function
+                .args()
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .collect::<Vec<_>>();
+            fields
+                .entry(path.clone())
+                .or_insert_with(BTreeMap::new)
+                .insert(name, names);
+        }
+    }
+
+    if fields.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "const OBJECT_ARGS = {};\n",
+        serde_json::to_string_pretty(&fields).unwrap()
+    ))
+}
+
+/// Check if the argument can be omitted, the backend receives `None` for missing `Option<T>` arguments.
+/// Only trailing arguments are optional, unless the procedure takes its arguments as an object.
+fn is_optional_arg(function: &Function, idx: usize, object_args: bool) -> bool {
+    let is_nullable = |(_, dt): &(_, DataType)| matches!(dt, DataType::Nullable(_));
+    match object_args {
+        true => is_nullable(&function.args()[idx]),
+        false => function.args()[idx..].iter().all(is_nullable),
+    }
+}
 
+/// Check if the procedure is called with a single object, `#[taurpc(args = "object")]`.
+fn takes_object_args(
+    object_args: &BTreeMap<String, Vec<String>>,
+    path: &str,
+    function: &Function,
+) -> bool {
     let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+    object_args
+        .get(path)
+        .is_some_and(|names| names.iter().any(|object_name| object_name == name))
+}
 
-    let mut field = Field::new(DataType::Reference(define(format!(
-        "({args}) => Promise<{return_ty}>"
-    ))));
-    field.docs = function.docs.clone();
-    Ok((name.to_string(), field))
+/// The docs of the function, followed by a `@param` tag for each documented argument.
+fn function_docs(function: &Function, arg_docs: Option<&BTreeMap<String, String>>) -> String {
+    // Doc comments keep the space after `///`, it's stripped to line up with the `@param` tags.
+    let mut docs = function
+        .docs
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    for (name, _) in function.args() {
+        let Some(arg_docs) = arg_docs.and_then(|args| args.get(name.as_ref())) else {
+            continue;
+        };
+
+        if !docs.is_empty() {
+            docs.push('\n');
+        }
+        docs.push_str(&format!("@param {name} {}", arg_docs.replace('\n', "\n  ")));
+    }
+    docs
 }
 
 fn render_reference_dt_for_phase(
@@ -268,8 +1192,8 @@ fn render_reference_dt_for_phase(
     exporter: &FrameworkExporter,
     format: &SpectaFormat,
 ) -> Result<String, Error> {
-    let dt1 = specta_serde::select_phase_datatype(dt, exporter.types, phase);
-    let dt = format.remapper.remap_dt(dt1);
+    let dt = specta_serde::select_phase_datatype(dt, exporter.types, phase);
+    let dt = format.remap(dt);
 
     render_reference_dt(&dt, exporter)
 }
@@ -279,8 +1203,7 @@ fn render_reference_dt_for_phase(
 fn render_reference_dt(dt: &DataType, exporter: &FrameworkExporter) -> Result<String, Error> {
     if let DataType::Reference(Reference::Named(r)) = dt
         && let Some(ndt) = exporter.types.get(r)
-        && ndt.name == "TAURI_CHANNEL"
-        && ndt.module_path.starts_with("tauri::")
+        && is_tauri_channel(ndt)
     {
         let generic = if let NamedReferenceType::Reference { generics, .. } = &r.inner {
             if let Some((_, dt)) = generics.first() {
@@ -299,19 +1222,32 @@ fn render_reference_dt(dt: &DataType, exporter: &FrameworkExporter) -> Result<St
     } else {
         match &dt {
             DataType::Reference(r) => exporter.reference(r),
+            // Inlining a nullable type also inlines the named type inside of it.
+            DataType::Nullable(inner) => match render_reference_dt(inner, exporter)? {
+                // `()` is already rendered as `null`.
+                inner if inner == "null" => Ok(inner),
+                inner => Ok(format!("{inner} | null")),
+            },
             dt => exporter.inline(dt),
         }
     }
 }
 
+fn is_tauri_channel(ndt: &NamedDataType) -> bool {
+    ndt.name == "TAURI_CHANNEL" && ndt.module_path.starts_with("tauri::")
+}
+
+fn is_std_result(ndt: &NamedDataType) -> bool {
+    ndt.name == "Result" && (ndt.module_path == "std::result" || ndt.module_path == "core::result")
+}
+
 fn extract_std_result<'a>(
     dt: &'a DataType,
     types: &'a Types,
 ) -> Option<(&'a DataType, &'a DataType)> {
     if let DataType::Reference(Reference::Named(r)) = dt
         && let Some(ndt) = types.get(r)
-        && ndt.name == "Result"
-        && (ndt.module_path == "std::result" || ndt.module_path == "core::result")
+        && is_std_result(ndt)
         && let NamedReferenceType::Reference { generics, .. } = &r.inner
         && let [(_, ok), (_, err), ..] = generics.as_slice()
     {
@@ -329,10 +1265,78 @@ impl<R: tauri::Runtime> Exportable<R> for crate::Router<R> {
         BTreeMap<String, Vec<Function>>,
         BTreeMap<String, String>,
     ) {
-        (
-            self.types.clone(),
-            self.fns_map.clone(),
-            self.args_map_json.clone(),
-        )
+        let mut fns_map = self.fns_map.clone();
+        let mut args_map_json = self.args_map_json.clone();
+        // The procedures registered with `Router::procedure` are added to the procedures of the handlers.
+        for (path, functions) in &self.procedure_fns {
+            let name = |f: &Function| f.name().split_once("_taurpc_fn__").unwrap().1.to_string();
+            let mut args_map: BTreeMap<String, Vec<String>> = args_map_json
+                .get(path)
+                .map(|args| serde_json::from_str(args).unwrap())
+                .unwrap_or_default();
+            for function in functions {
+                let args = function.args().iter().map(|(arg, _)| arg.to_string());
+                args_map.insert(name(function), args.collect());
+            }
+            args_map_json.insert(path.clone(), serde_json::to_string(&args_map).unwrap());
+
+            // They take precedence over the procedures of a handler with the same name, see `validate`.
+            let path_functions = fns_map.entry(path.clone()).or_default();
+            path_functions.retain(|f| !functions.iter().any(|function| name(function) == name(f)));
+            path_functions.extend(functions.iter().cloned());
+        }
+
+        (self.types.clone(), fns_map, args_map_json)
+    }
+
+    fn events(&self) -> BTreeMap<String, Vec<String>> {
+        self.events.clone()
+    }
+
+    fn object_args(&self) -> BTreeMap<String, Vec<String>> {
+        self.object_args.clone()
+    }
+
+    fn docs(&self) -> BTreeMap<String, PathDocs> {
+        self.docs.clone()
+    }
+
+    fn bigint(&self) -> BigIntExport {
+        self.bigint.mode
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        fn name(f: &Function) -> &str {
+            f.name().split_once("_taurpc_fn__").unwrap().1
+        }
+
+        let mut errors = self.procedure_errors.clone();
+        for (path, functions) in &self.procedure_fns {
+            let Some(handler_functions) = self.fns_map.get(path) else {
+                continue;
+            };
+            for function in functions {
+                if handler_functions.iter().any(|f| name(f) == name(function)) {
+                    let full_path = match path.as_str() {
+                        "" => name(function).to_string(),
+                        path => format!("{path}.{}", name(function)),
+                    };
+                    errors.push(format!(
+                        "`{full_path}` is also a procedure of the handler at `{path}`"
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(Error::framework(
+            "invalid procedures are registered with `Router::procedure`",
+            format!(
+                "{}. Use a unique path for every procedure",
+                errors.join("; ")
+            ),
+        ))
     }
 }
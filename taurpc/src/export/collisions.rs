@@ -0,0 +1,67 @@
+use specta::Types;
+use specta_typescript::Error;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// How types with the same name from different Rust modules are exported, see [`Exporter::type_collisions`](super::Exporter::type_collisions).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeCollisions {
+    /// Fail the export with an error that lists the Rust paths of the types.
+    #[default]
+    Error,
+    /// Prefix the names of the colliding types with their module path, e.g. `my_app_users_User`.
+    /// Types with a unique name keep their name.
+    ModulePrefix,
+}
+
+/// Check that every exported type has a unique name, colliding types are renamed with [`TypeCollisions::ModulePrefix`].
+pub(super) fn resolve_collisions(
+    types: &mut Types,
+    collisions: TypeCollisions,
+) -> Result<(), Error> {
+    let mut module_paths: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    // Types that are inlined are not exported, so their names can't collide.
+    for ndt in types.into_unsorted_iter().filter(|ndt| ndt.ty.is_some()) {
+        module_paths
+            .entry(ndt.name.to_string())
+            .or_default()
+            .insert(ndt.module_path.to_string());
+    }
+    module_paths.retain(|_, module_paths| module_paths.len() > 1);
+
+    if module_paths.is_empty() {
+        return Ok(());
+    }
+
+    match collisions {
+        TypeCollisions::Error => {
+            let collisions = module_paths
+                .iter()
+                .map(|(name, module_paths)| {
+                    let paths = module_paths
+                        .iter()
+                        .map(|module_path| format!("`{module_path}::{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!("`{name}` is used by {paths}")
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            Err(Error::framework(
+                "multiple types are exported with the same name",
+                format!(
+                    "{collisions}. Rename the types with `#[serde(rename = \"...\")]` or use `TypeCollisions::ModulePrefix`"
+                ),
+            ))
+        }
+        TypeCollisions::ModulePrefix => {
+            types.iter_mut(|ndt| {
+                if ndt.ty.is_some() && module_paths.contains_key(ndt.name.as_ref()) {
+                    ndt.name =
+                        format!("{}_{}", ndt.module_path.replace("::", "_"), ndt.name).into();
+                }
+            });
+            Ok(())
+        }
+    }
+}
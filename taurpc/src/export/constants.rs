@@ -0,0 +1,88 @@
+use serde::Serialize;
+use specta::{Type, Types, datatype::DataType};
+use specta_serde::Phase;
+use specta_typescript::{Error, FrameworkExporter};
+
+use super::{Output, SpectaFormat, jsdoc, render_reference_dt_for_phase};
+use crate::BigIntExport;
+use crate::bigint;
+
+/// A Rust constant that can be exported with [`Exporter::constant`](super::Exporter::constant).
+///
+/// This is implemented by `#[taurpc::constant]` for a struct with the same name as the constant.
+pub trait Constant {
+    /// The name of the constant in the bindings.
+    const NAME: &'static str;
+
+    /// The doc comments of the constant.
+    const DOCS: &'static str;
+
+    /// The value of the constant.
+    fn value() -> impl Serialize + Type;
+}
+
+/// A constant with its type, the value is serialized when it is exported.
+#[derive(Debug, Clone)]
+pub(super) struct ExportedConstant {
+    name: &'static str,
+    docs: &'static str,
+    dt: DataType,
+    value: fn(BigIntExport) -> Result<String, String>,
+}
+
+impl ExportedConstant {
+    /// The types used by the constant are registered in `types`.
+    pub(super) fn new<C: Constant>(types: &mut Types) -> Self {
+        fn definition<T: Type>(_: &T, types: &mut Types) -> DataType {
+            T::definition(types)
+        }
+
+        Self {
+            name: C::NAME,
+            docs: C::DOCS,
+            dt: definition(&C::value(), types),
+            // Large integers are serialized like the values sent by the router.
+            value: |mode| bigint::to_js_literal(&C::value(), mode).map_err(|err| err.to_string()),
+        }
+    }
+}
+
+/// Render the constants as `export const`, with the type of their Rust value.
+pub(super) fn generate_constants(
+    constants: &[ExportedConstant],
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+    output: Output,
+) -> Result<String, Error> {
+    let mut out = Vec::new();
+    for constant in constants {
+        let name = constant.name;
+        let value = (constant.value)(format.bigint).map_err(|err| {
+            Error::framework(format!("failed to serialize the constant `{name}`"), err)
+        })?;
+        let ts_type =
+            render_reference_dt_for_phase(&constant.dt, Phase::Serialize, exporter, format)?;
+
+        let mut docs = constant
+            .docs
+            .lines()
+            .map(|line| jsdoc::escape(line.trim()))
+            .collect::<Vec<_>>();
+        // The type is annotated with JSDoc in JavaScript.
+        if output == Output::Javascript {
+            docs.push(format!("@type {{{ts_type}}}"));
+        }
+        let mut rendered = match docs.is_empty() {
+            true => String::new(),
+            false => format!("/**\n * {}\n */\n", docs.join("\n * ")),
+        };
+        rendered.push_str(&match output {
+            Output::Typescript => format!("export const {name}: {ts_type} = {value};\n"),
+            Output::Javascript => format!("export const {name} = {value};\n"),
+            Output::Declarations => format!("export declare const {name}: {ts_type};\n"),
+        });
+        out.push(rendered);
+    }
+
+    Ok(out.join("\n"))
+}
@@ -0,0 +1,345 @@
+use specta::datatype::Function;
+use std::collections::BTreeMap;
+
+use super::{Output, router_type_ref};
+
+/// Frontend framework integrations that can be generated by [`Exporter::framework`](super::Exporter::framework).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Framework {
+    /// `useProcedureQuery`, `useProcedureMutation` and `useEvent` hooks, requires `react`.
+    React,
+    /// `procedureQuery`, `procedureMutation` and `eventStore` stores, requires `svelte`.
+    Svelte,
+    /// `procedureKey`, `procedureQueryOptions` and `procedureMutationOptions` factories for TanStack Query.
+    /// These are plain objects, so they work with any TanStack Query adapter.
+    TanstackQuery,
+}
+
+static BOILERPLATE_TS_PROCEDURE_TYPES: &str = r#"
+export type ProcedureInput<P extends keyof Procedures> = Parameters<Procedures[P]>
+export type ProcedureOutput<P extends keyof Procedures> = Awaited<ReturnType<Procedures[P]>>
+export type ProcedureEventListener<E extends keyof Events> = (...args: Parameters<Events[E]>) => void
+export type ProcedureState<P extends keyof Procedures> = { data?: ProcedureOutput<P>; error?: unknown; isLoading: boolean }
+"#;
+
+// The helpers are written once in TypeScript, the parts between `«` and `»` are types, see `render`.
+static BOILERPLATE_PROCEDURES: &str = r#"
+let proxy«: ReturnType<typeof createTauRPCProxy> | undefined»
+
+«// eslint-disable-next-line @typescript-eslint/no-explicit-any»
+const getProcedure = (path«: string»)«: any» =>
+	«// eslint-disable-next-line @typescript-eslint/no-explicit-any»
+	path.split('.').reduce((layer«: any», key) => layer[key], proxy ??= createTauRPCProxy())
+
+/** Call a procedure by its path, e.g. `callProcedure('api.ui.trigger')`. */
+export const callProcedure = «<P extends keyof Procedures>»(path«: P», ...args«: ProcedureInput<P>»)«: Promise<ProcedureOutput<P>>» =>
+	getProcedure(path)(...args)
+
+/** Listen for an event by its path, returns a function to stop listening. */
+export const listenEvent = «<E extends keyof Events>»(path«: E», listener«: ProcedureEventListener<E>»)«: Promise<UnlistenFn>» =>
+	getProcedure(path).on(listener)
+"#;
+
+static BOILERPLATE_REACT: &str = r#"
+import { useCallback, useEffect, useRef, useState } from 'react'
+
+/** Call a procedure when the component mounts, and again when the path or arguments change. */
+export const useProcedureQuery = «<P extends keyof Procedures>»(path«: P», ...args«: ProcedureInput<P>»)«: ProcedureState<P> & { refetch: () => Promise<void> }» => {
+	const [state, setState] = useState«<ProcedureState<P>>»({ isLoading: true })
+	const argsRef = useRef(args)
+	argsRef.current = args
+	const key = JSON.stringify([path, args])
+
+	const refetch = useCallback(async () => {
+		setState((state) => ({ ...state, isLoading: true }))
+		try {
+			const data = await callProcedure(path, ...argsRef.current)
+			setState({ data, isLoading: false })
+		} catch (error) {
+			setState({ error, isLoading: false })
+		}
+	}, [key])
+
+	useEffect(() => {
+		void refetch()
+	}, [refetch])
+
+	return { ...state, refetch }
+}
+
+/** Call a procedure on demand with `mutate`. */
+export const useProcedureMutation = «<P extends keyof Procedures>»(path«: P»)«: ProcedureState<P> & { mutate: (...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> }» => {
+	const [state, setState] = useState«<ProcedureState<P>>»({ isLoading: false })
+
+	const mutate = useCallback(async (...args«: ProcedureInput<P>») => {
+		setState({ isLoading: true })
+		try {
+			const data = await callProcedure(path, ...args)
+			setState({ data, isLoading: false })
+			return data
+		} catch (error) {
+			setState({ error, isLoading: false })
+			throw error
+		}
+	}, [path])
+
+	return { ...state, mutate }
+}
+
+/** Listen for an event while the component is mounted. */
+export const useEvent = «<E extends keyof Events>»(path«: E», listener«: ProcedureEventListener<E>»)«: void» => {
+	const listenerRef = useRef(listener)
+	listenerRef.current = listener
+
+	useEffect(() => {
+		const unlisten = listenEvent(path, «(»(...args«: Parameters<Events[E]>») => listenerRef.current(...args)«) as ProcedureEventListener<E>»)
+		return () => {
+			void unlisten.then((unlisten) => unlisten())
+		}
+	}, [path])
+}
+"#;
+
+static BOILERPLATE_SVELTE: &str = r#"
+import { readable, writable } from 'svelte/store'
+«import type { Readable } from 'svelte/store'»
+
+/** A store that calls the procedure once it gets its first subscriber. */
+export const procedureQuery = «<P extends keyof Procedures>»(path«: P», ...args«: ProcedureInput<P>»)«: Readable<ProcedureState<P>>» =>
+	readable«<ProcedureState<P>>»({ isLoading: true }, (set) => {
+		let active = true
+		callProcedure(path, ...args).then(
+			(data) => active && set({ data, isLoading: false }),
+			(error) => active && set({ error, isLoading: false }),
+		)
+		return () => {
+			active = false
+		}
+	})
+
+/** A store with a `mutate` function to call the procedure on demand. */
+export const procedureMutation = «<P extends keyof Procedures>»(path«: P»)«: Readable<ProcedureState<P>> & { mutate: (...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> }» => {
+	const { subscribe, set } = writable«<ProcedureState<P>>»({ isLoading: false })
+
+	const mutate = async (...args«: ProcedureInput<P>») => {
+		set({ isLoading: true })
+		try {
+			const data = await callProcedure(path, ...args)
+			set({ data, isLoading: false })
+			return data
+		} catch (error) {
+			set({ error, isLoading: false })
+			throw error
+		}
+	}
+
+	return { subscribe, mutate }
+}
+
+/** A store containing the arguments of the last event, it stops listening when it has no subscribers left. */
+export const eventStore = «<E extends keyof Events>»(path«: E»)«: Readable<Parameters<Events[E]> | undefined>» =>
+	readable«<Parameters<Events[E]> | undefined>»(undefined, (set) => {
+		const unlisten = listenEvent(path, «(»(...args«: Parameters<Events[E]>») => set(args)«) as ProcedureEventListener<E>»)
+		return () => {
+			void unlisten.then((unlisten) => unlisten())
+		}
+	})
+"#;
+
+static BOILERPLATE_TANSTACK_QUERY: &str = r#"
+/** The query key of a procedure, e.g. `['api', 'ui', 'trigger', []]`. Invalidate `['api']` to invalidate all procedures in `api`. */
+export const procedureKey = «<P extends keyof Procedures>»(path«: P», args«?: ProcedureInput<P>»)«: (string | ProcedureInput<P>)[]» =>
+	args ? [...path.split('.'), args] : path.split('.')
+
+export const procedureQueryOptions = «<P extends keyof Procedures>»(path«: P», ...args«: ProcedureInput<P>»)«: { queryKey: (string | ProcedureInput<P>)[]; queryFn: () => Promise<ProcedureOutput<P>> }» => ({
+	queryKey: procedureKey(path, args),
+	queryFn: () => callProcedure(path, ...args),
+})
+
+export const procedureMutationOptions = «<P extends keyof Procedures>»(path«: P»)«: { mutationKey: (string | ProcedureInput<P>)[]; mutationFn: (args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> }» => ({
+	mutationKey: procedureKey(path),
+	mutationFn: (args«: ProcedureInput<P>») => callProcedure(path, ...args),
+})
+"#;
+
+impl Framework {
+    fn boilerplate(&self) -> &'static str {
+        match self {
+            Self::React => BOILERPLATE_REACT,
+            Self::Svelte => BOILERPLATE_SVELTE,
+            Self::TanstackQuery => BOILERPLATE_TANSTACK_QUERY,
+        }
+    }
+}
+
+/// A line that only contains types, e.g. `«import type { Readable } from 'svelte/store'»`.
+fn type_line(line: &str) -> Option<&str> {
+    let types = line.trim().strip_prefix('«')?.strip_suffix('»')?;
+    (!types.contains('«')).then_some(types)
+}
+
+/// Render a helper template for the output.
+///
+/// TypeScript keeps the types, JavaScript leaves them out. The declarations only keep the type imports and the
+/// exported constants, the type of a constant is taken from its arguments and the return type that follows them.
+fn render(template: &str, output: Output) -> String {
+    match output {
+        Output::Typescript => template.replace(['«', '»'], ""),
+        Output::Javascript => {
+            let mut out = String::new();
+            for line in template.split_inclusive('\n') {
+                if type_line(line).is_some() {
+                    continue;
+                }
+                let mut rest = line;
+                while let Some((code, types)) = rest.split_once('«') {
+                    out.push_str(code);
+                    rest = types.split_once('»').unwrap().1;
+                }
+                out.push_str(rest);
+            }
+            out
+        }
+        Output::Declarations => {
+            let mut declarations = Vec::new();
+            let mut doc = None;
+            for line in template.lines() {
+                if let Some(header) = line.strip_prefix("export const ") {
+                    let (name, signature) = header.split_once(" = ").unwrap();
+                    let end = signature.find("» =>").unwrap();
+                    let start = signature[..end].rfind("«: ").unwrap();
+                    let args = signature[..start].replace(['«', '»'], "");
+                    let returns = &signature[start + "«: ".len()..end];
+                    let doc = doc.take().map(|doc| format!("{doc}\n")).unwrap_or_default();
+                    declarations.push(format!(
+                        "{doc}export declare const {name}: {args} => {returns}"
+                    ));
+                } else if line.starts_with("/**") {
+                    doc = Some(line);
+                } else {
+                    doc = None;
+                    if let Some(types) =
+                        type_line(line).filter(|types| types.starts_with("import type"))
+                    {
+                        declarations.push(types.to_string());
+                    }
+                }
+            }
+            format!("\n{}\n", declarations.join("\n\n"))
+        }
+    }
+}
+
+/// Render the `Procedures` and `Events` types, the shared helpers and the selected framework bindings.
+pub(super) fn generate_frameworks(
+    frameworks: &[Framework],
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    output: Output,
+) -> String {
+    if output == Output::Javascript {
+        let mut out = render(BOILERPLATE_PROCEDURES, output);
+        for framework in frameworks {
+            out.push_str(&render(framework.boilerplate(), output));
+        }
+        return out;
+    }
+
+    let mut procedures = String::new();
+    let mut event_procedures = String::new();
+
+    for (path, path_functions) in functions {
+        let mut names = path_functions
+            .iter()
+            .map(|f| f.name().split_once("_taurpc_fn__").unwrap().1)
+            .collect::<Vec<_>>();
+        names.sort();
+
+        let path_events = events.get(path);
+        for name in names {
+            let procedure_path = if path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{path}.{name}")
+            };
+            let field = format!(
+                "\t\"{procedure_path}\": {},\n",
+                router_type_ref(events, path, name)
+            );
+
+            if path_events.is_some_and(|events| events.iter().any(|event| event == name)) {
+                event_procedures.push_str(&field);
+            } else {
+                procedures.push_str(&field);
+            }
+        }
+    }
+
+    let mut out = String::from("\nimport type { UnlistenFn } from '@tauri-apps/api/event'\n\n");
+    out.push_str(&format!("export type Procedures = {{\n{procedures}}}\n\n"));
+    out.push_str(&format!("export type Events = {{\n{event_procedures}}}\n"));
+    out.push_str(BOILERPLATE_TS_PROCEDURE_TYPES);
+    out.push_str(&render(BOILERPLATE_PROCEDURES, output));
+
+    for framework in frameworks {
+        out.push_str(&render(framework.boilerplate(), output));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEMPLATE: &str = r#"
+import { readable } from 'svelte/store'
+«import type { Readable } from 'svelte/store'»
+
+«// eslint-disable-next-line»
+const double = (value«: number»)«: number» => value * 2
+
+/** Read a value. */
+export const read = «<T>»(value«: T»)«: Readable<T>» =>
+	readable«<T>»(value)
+"#;
+
+    #[test]
+    fn render_outputs() {
+        assert_eq!(
+            render(TEMPLATE, Output::Typescript),
+            r#"
+import { readable } from 'svelte/store'
+import type { Readable } from 'svelte/store'
+
+// eslint-disable-next-line
+const double = (value: number): number => value * 2
+
+/** Read a value. */
+export const read = <T>(value: T): Readable<T> =>
+	readable<T>(value)
+"#
+        );
+        assert_eq!(
+            render(TEMPLATE, Output::Javascript),
+            r#"
+import { readable } from 'svelte/store'
+
+const double = (value) => value * 2
+
+/** Read a value. */
+export const read = (value) =>
+	readable(value)
+"#
+        );
+        assert_eq!(
+            render(TEMPLATE, Output::Declarations),
+            r#"
+import type { Readable } from 'svelte/store'
+
+/** Read a value. */
+export declare const read: <T>(value: T) => Readable<T>
+"#
+        );
+    }
+}
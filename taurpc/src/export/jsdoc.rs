@@ -0,0 +1,70 @@
+use specta::datatype::NamedDataType;
+use specta_typescript::{Error, FrameworkExporter, primitives};
+
+/// Render the exported types as JSDoc `@typedef`s, for the JavaScript output.
+pub(super) fn generate_typedefs(exporter: &FrameworkExporter) -> Result<String, Error> {
+    let typedefs = exporter
+        .types
+        .into_sorted_iter()
+        .filter(|ndt| ndt.ty.is_some())
+        .map(|ndt| typedef(ndt, exporter))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(typedefs.join("\n"))
+}
+
+pub(super) fn typedef(ndt: &NamedDataType, exporter: &FrameworkExporter) -> Result<String, Error> {
+    let Some(ty) = &ndt.ty else {
+        return Ok(String::new());
+    };
+
+    let mut out = String::from("/**\n");
+    for line in ndt.docs.lines() {
+        out.push_str(&format!(" * {}\n", escape(line.trim())));
+    }
+    if let Some(deprecated) = &ndt.deprecated {
+        match &deprecated.note {
+            Some(note) => out.push_str(&format!(" * @deprecated {}\n", escape(note))),
+            None => out.push_str(" * @deprecated\n"),
+        }
+    }
+    if !ndt.generics.is_empty() {
+        let generics = ndt
+            .generics
+            .iter()
+            .map(|g| g.name.as_ref())
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!(" * @template {generics}\n"));
+    }
+
+    // The types are already mapped by the format, mapping them again would tag enums repeatedly.
+    let ts_type = strip_comments(&primitives::inline(exporter, exporter.types, ty)?).join("\n * ");
+    out.push_str(&format!(" * @typedef {{{ts_type}}} {}\n */\n", ndt.name));
+
+    Ok(out)
+}
+
+/// Comments can't be nested in the JSDoc comment, the rendered types contain the docs of fields.
+fn strip_comments(ts_type: &str) -> Vec<&str> {
+    let mut in_comment = false;
+    ts_type
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            if !in_comment && trimmed.starts_with("/*") {
+                in_comment = !trimmed.contains("*/");
+                return false;
+            }
+            if in_comment {
+                in_comment = !trimmed.contains("*/");
+                return false;
+            }
+            true
+        })
+        .collect()
+}
+
+pub(super) fn escape(text: &str) -> String {
+    text.replace("*/", "*\\/")
+}
@@ -0,0 +1,349 @@
+use specta::datatype::{
+    DataType, Deprecated, Fields, Function, NamedDataType, NamedReferenceType, Reference,
+};
+use specta_serde::Phase;
+use specta_typescript::{Error, FrameworkExporter, primitives};
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    SpectaFormat, extract_std_result, is_optional_arg, is_std_result, is_tauri_channel,
+    render_reference_dt_for_phase, takes_object_args,
+};
+
+const MARKDOWN_HEADER: &str =
+    "<!-- This file has been generated by TauRPC. Do not edit this file manually. -->";
+
+/// Render the API reference, the procedures are grouped by path and link to the type definitions.
+pub(super) fn generate_reference(
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    object_args: &BTreeMap<String, Vec<String>>,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+) -> Result<String, Error> {
+    let renderer = Renderer { exporter, format };
+
+    let mut out = format!("{MARKDOWN_HEADER}\n\n# API reference\n");
+    for (path, path_functions) in functions {
+        let mut path_functions: Vec<_> = path_functions
+            .iter()
+            .map(|f| (f.name().split_once("_taurpc_fn__").unwrap().1, f))
+            .collect();
+        path_functions.sort_by(|a, b| a.0.cmp(b.0));
+
+        let (events, procedures): (Vec<_>, Vec<_>) =
+            path_functions.into_iter().partition(|(name, _)| {
+                events
+                    .get(path)
+                    .is_some_and(|events| events.iter().any(|event| event == name))
+            });
+
+        match path.as_str() {
+            "" => out.push_str("\n## Root\n"),
+            path => out.push_str(&format!("\n## `{path}`\n")),
+        }
+        if !procedures.is_empty() {
+            out.push_str("\n### Procedures\n");
+            for (name, function) in procedures {
+                let object_args = takes_object_args(object_args, path, function);
+                out.push_str(&renderer.procedure(path, name, function, object_args)?);
+            }
+        }
+        if !events.is_empty() {
+            out.push_str("\n### Events\n");
+            for (name, function) in events {
+                out.push_str(&renderer.event(path, name, function)?);
+            }
+        }
+    }
+
+    let types: Vec<_> = exporter
+        .types
+        .into_sorted_iter()
+        .filter(|ndt| ndt.ty.is_some() && !is_tauri_channel(ndt) && !is_std_result(ndt))
+        .collect();
+    if !types.is_empty() {
+        out.push_str("\n## Types\n");
+        for ndt in types {
+            out.push_str(&renderer.type_definition(ndt)?);
+        }
+    }
+
+    Ok(out)
+}
+
+struct Renderer<'a, 'b> {
+    exporter: &'a FrameworkExporter<'b>,
+    format: &'a SpectaFormat,
+}
+
+impl Renderer<'_, '_> {
+    fn procedure(
+        &self,
+        path: &str,
+        name: &str,
+        function: &Function,
+        object_args: bool,
+    ) -> Result<String, Error> {
+        let args = self.args(function, Phase::Deserialize)?;
+        let (output, error) = match function.result() {
+            Some(result) => match extract_std_result(result, self.exporter.types) {
+                Some((ok, err)) => (
+                    self.ty(ok, Phase::Serialize)?,
+                    Some(self.ty(err, Phase::Serialize)?),
+                ),
+                None => (self.ty(result, Phase::Serialize)?, None),
+            },
+            None => (Type::void(), None),
+        };
+
+        let signature = args
+            .iter()
+            .enumerate()
+            .map(
+                |(idx, (name, ty))| match is_optional_arg(function, idx, object_args) {
+                    true => format!("{name}?: {}", ty.ts_type),
+                    false => format!("{name}: {}", ty.ts_type),
+                },
+            )
+            .collect::<Vec<_>>();
+        let signature = match object_args && !args.is_empty() {
+            true => format!("args: {{ {} }}", signature.join("; ")),
+            false => signature.join(", "),
+        };
+
+        let mut out = format!("\n#### `{}`\n\n", procedure_path(path, name));
+        out.push_str(&describe(&function.docs, function.deprecated.as_ref()));
+        out.push_str(&format!(
+            "```ts\n{}({signature}): Promise<{}>\n```\n\n",
+            procedure_path(path, name),
+            output.ts_type
+        ));
+        out.push_str(&args_table("Argument", &args));
+        if !args.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("**Returns:** {}\n", output.link()));
+        if let Some(error) = error {
+            out.push_str(&format!("\n**Errors:** {}\n", error.link()));
+        }
+
+        Ok(out)
+    }
+
+    fn event(&self, path: &str, name: &str, function: &Function) -> Result<String, Error> {
+        let args = self.args(function, Phase::Serialize)?;
+        let signature = args
+            .iter()
+            .map(|(name, ty)| format!("{name}: {}", ty.ts_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut out = format!("\n#### `{}`\n\n", procedure_path(path, name));
+        out.push_str(&describe(&function.docs, function.deprecated.as_ref()));
+        out.push_str(&format!(
+            "```ts\n{}.on(({signature}) => void)\n```\n",
+            procedure_path(path, name)
+        ));
+        if !args.is_empty() {
+            out.push('\n');
+            out.push_str(&args_table("Payload", &args));
+        }
+
+        Ok(out)
+    }
+
+    fn type_definition(&self, ndt: &NamedDataType) -> Result<String, Error> {
+        let mut out = format!("\n### `{}`\n\n", ndt.name);
+        out.push_str(&describe(&ndt.docs, ndt.deprecated.as_ref()));
+        // The types are already mapped by the format, mapping them again would tag enums repeatedly.
+        let ts_type =
+            primitives::export(self.exporter, self.exporter.types, std::iter::once(ndt), "")?;
+        out.push_str(&format!("```ts\n{}\n```\n", ts_type.trim_end_matches('\n')));
+
+        let mut references = BTreeSet::new();
+        if let Some(ty) = &ndt.ty {
+            self.collect_references(ty, &mut references);
+        }
+        references.remove(ndt.name.as_ref());
+        if !references.is_empty() {
+            out.push_str(&format!(
+                "\n**References:** {}\n",
+                references
+                    .iter()
+                    .map(|name| link(name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        Ok(out)
+    }
+
+    fn args(&self, function: &Function, phase: Phase) -> Result<Vec<(String, Type)>, Error> {
+        function
+            .args()
+            .iter()
+            .map(|(name, dt)| Ok((name.to_string(), self.ty(dt, phase)?)))
+            .collect()
+    }
+
+    fn ty(&self, dt: &DataType, phase: Phase) -> Result<Type, Error> {
+        let ts_type = render_reference_dt_for_phase(dt, phase, self.exporter, self.format)?;
+
+        let dt = specta_serde::select_phase_datatype(dt, self.exporter.types, phase);
+        let mut references = BTreeSet::new();
+        self.collect_references(&self.format.remap(dt), &mut references);
+
+        Ok(Type {
+            ts_type,
+            references,
+        })
+    }
+
+    /// Collect the names of the exported types that are used by `dt`.
+    fn collect_references(&self, dt: &DataType, references: &mut BTreeSet<String>) {
+        match dt {
+            DataType::List(list) => self.collect_references(&list.ty, references),
+            DataType::Map(map) => {
+                self.collect_references(map.key_ty(), references);
+                self.collect_references(map.value_ty(), references);
+            }
+            DataType::Nullable(inner) => self.collect_references(inner, references),
+            DataType::Struct(strct) => self.collect_field_references(&strct.fields, references),
+            DataType::Enum(enm) => enm.variants.iter().for_each(|(_, variant)| {
+                self.collect_field_references(&variant.fields, references)
+            }),
+            DataType::Tuple(tuple) => tuple
+                .elements
+                .iter()
+                .for_each(|dt| self.collect_references(dt, references)),
+            DataType::Intersection(parts) => parts
+                .iter()
+                .for_each(|dt| self.collect_references(dt, references)),
+            DataType::Reference(Reference::Named(r)) => match &r.inner {
+                NamedReferenceType::Reference { generics, .. } => {
+                    if let Some(ndt) = self.exporter.types.get(r)
+                        && ndt.ty.is_some()
+                        && !is_tauri_channel(ndt)
+                        && !is_std_result(ndt)
+                    {
+                        references.insert(ndt.name.to_string());
+                    }
+                    generics
+                        .iter()
+                        .for_each(|(_, dt)| self.collect_references(dt, references));
+                }
+                NamedReferenceType::Inline { dt, .. } => self.collect_references(dt, references),
+                NamedReferenceType::Recursive(_) => {}
+            },
+            DataType::Primitive(_) | DataType::Generic(_) | DataType::Reference(_) => {}
+        }
+    }
+
+    fn collect_field_references(&self, fields: &Fields, references: &mut BTreeSet<String>) {
+        match fields {
+            Fields::Unit => {}
+            Fields::Unnamed(unnamed) => unnamed
+                .fields
+                .iter()
+                .filter_map(|field| field.ty.as_ref())
+                .for_each(|dt| self.collect_references(dt, references)),
+            Fields::Named(named) => named
+                .fields
+                .iter()
+                .filter_map(|(_, field)| field.ty.as_ref())
+                .for_each(|dt| self.collect_references(dt, references)),
+        }
+    }
+}
+
+/// A rendered TypeScript type and the exported types it uses.
+struct Type {
+    ts_type: String,
+    references: BTreeSet<String>,
+}
+
+impl Type {
+    fn void() -> Self {
+        Self {
+            ts_type: "void".into(),
+            references: BTreeSet::new(),
+        }
+    }
+
+    /// The type, with links to the definitions of the types it uses.
+    fn link(&self) -> String {
+        if self.references.len() == 1 && self.references.contains(&self.ts_type) {
+            return link(&self.ts_type);
+        }
+
+        let mut out = format!("`{}`", self.ts_type);
+        if !self.references.is_empty() {
+            let links = self
+                .references
+                .iter()
+                .map(|name| link(name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(" ({links})"));
+        }
+        out
+    }
+}
+
+fn args_table(heading: &str, args: &[(String, Type)]) -> String {
+    if args.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("| {heading} | Type |\n| --- | --- |\n");
+    for (name, ty) in args {
+        // Pipes have to be escaped in tables, also inside of code.
+        out.push_str(&format!(
+            "| `{name}` | {} |\n",
+            ty.link().replace('|', "\\|")
+        ));
+    }
+    out
+}
+
+fn describe(docs: &str, deprecated: Option<&Deprecated>) -> String {
+    let mut out = String::new();
+    if let Some(deprecated) = deprecated {
+        match &deprecated.note {
+            Some(note) => out.push_str(&format!("**Deprecated:** {note}\n\n")),
+            None => out.push_str("**Deprecated**\n\n"),
+        }
+    }
+
+    let docs = docs
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let docs = docs.trim();
+    if !docs.is_empty() {
+        out.push_str(docs);
+        out.push_str("\n\n");
+    }
+    out
+}
+
+fn procedure_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// Link to the definition of a type, the anchor matches the one generated for its heading.
+fn link(name: &str) -> String {
+    let anchor: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+    format!("[`{name}`](#{anchor})")
+}
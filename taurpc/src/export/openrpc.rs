@@ -0,0 +1,395 @@
+use serde_json::{Map, Value, json};
+use specta::{
+    Type, Types,
+    datatype::{
+        DataType, Deprecated, Fields, Function, Generic, NamedDataType, NamedReferenceType,
+        OpaqueReference, Primitive, Reference,
+    },
+};
+use specta_serde::Phase;
+use specta_typescript::{Error, FrameworkExporter};
+use std::collections::BTreeMap;
+
+use super::{SpectaFormat, args_phase, extract_std_result, is_tauri_channel};
+
+const OPENRPC_VERSION: &str = "1.3.2";
+
+/// Render the OpenRPC document describing all procedures, events are described as notifications.
+pub(super) fn generate_document(
+    info: &(String, String),
+    functions: &BTreeMap<String, Vec<Function>>,
+    args_map: &BTreeMap<String, String>,
+    events: &BTreeMap<String, Vec<String>>,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+) -> Result<String, Error> {
+    let renderer = SchemaRenderer { exporter };
+
+    let mut methods = Vec::new();
+    for (path, path_functions) in functions {
+        let arg_names: BTreeMap<String, Vec<String>> = args_map
+            .get(path)
+            .and_then(|args| serde_json::from_str(args).ok())
+            .unwrap_or_default();
+
+        let mut path_functions: Vec<_> = path_functions
+            .iter()
+            .map(|f| (f.name().split_once("_taurpc_fn__").unwrap().1, f))
+            .collect();
+        path_functions.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (name, function) in path_functions {
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+            let procedure = if path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{path}.{name}")
+            };
+
+            methods.push(renderer.method(
+                &procedure,
+                path,
+                function,
+                arg_names.get(name).map(Vec::as_slice).unwrap_or_default(),
+                is_event,
+                format,
+            )?);
+        }
+    }
+
+    let mut schemas = Map::new();
+    for ndt in exporter.types.into_sorted_iter() {
+        // Generic types are inlined, JSON Schema has no generics.
+        if let Some(ty) = &ndt.ty
+            && ndt.generics.is_empty()
+            && !is_tauri_channel(ndt)
+        {
+            let mut schema = renderer.schema(ty, &[])?;
+            describe(&mut schema, &ndt.docs, ndt.deprecated.as_ref());
+            schemas.insert(ndt.name.to_string(), schema);
+        }
+    }
+
+    let (title, version) = info;
+    let document = json!({
+        "openrpc": OPENRPC_VERSION,
+        "info": { "title": title, "version": version },
+        "methods": methods,
+        "components": { "schemas": schemas },
+    });
+
+    serde_json::to_string_pretty(&document)
+        .map_err(|err| Error::framework("failed to serialize the OpenRPC document", err))
+}
+
+struct SchemaRenderer<'a, 'b> {
+    exporter: &'a FrameworkExporter<'b>,
+}
+
+impl SchemaRenderer<'_, '_> {
+    fn method(
+        &self,
+        procedure: &str,
+        path: &str,
+        function: &Function,
+        arg_names: &[String],
+        is_event: bool,
+        format: &SpectaFormat,
+    ) -> Result<Value, Error> {
+        let params = function
+            .args()
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, dt))| {
+                let name = arg_names.get(idx).map(String::as_str).unwrap_or(name);
+                // Missing `Option<T>` arguments are received as `None`, events always send them.
+                let required = is_event || !matches!(dt, DataType::Nullable(_));
+                Ok(json!({
+                    "name": name,
+                    "required": required,
+                    "schema": self.procedure_schema(dt, args_phase(is_event), format)?,
+                }))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut method = json!({
+            "name": procedure,
+            "params": params,
+            "paramStructure": "by-name",
+        });
+        if !path.is_empty() {
+            method["tags"] = json!([{ "name": path }]);
+        }
+        describe(&mut method, &function.docs, function.deprecated.as_ref());
+
+        // Methods without a result are notifications.
+        if !is_event {
+            let (result, error) = match function.result() {
+                Some(result) => match extract_std_result(result, self.exporter.types) {
+                    Some((ok, err)) => (
+                        self.procedure_schema(ok, Phase::Serialize, format)?,
+                        Some(self.procedure_schema(err, Phase::Serialize, format)?),
+                    ),
+                    None => (
+                        self.procedure_schema(result, Phase::Serialize, format)?,
+                        None,
+                    ),
+                },
+                None => (json!({ "type": "null" }), None),
+            };
+
+            method["result"] = json!({ "name": "result", "schema": result });
+            // Errors are returned as-is by Tauri, not as JSON-RPC error objects.
+            if let Some(error) = error {
+                method["x-error"] = json!({ "name": "error", "schema": error });
+            }
+        }
+
+        Ok(method)
+    }
+
+    fn procedure_schema(
+        &self,
+        dt: &DataType,
+        phase: Phase,
+        format: &SpectaFormat,
+    ) -> Result<Value, Error> {
+        let dt = specta_serde::select_phase_datatype(dt, self.exporter.types, phase);
+        let dt = format.remap(dt);
+
+        // Channels are sent as an id, the messages are described by `x-channel`.
+        if let DataType::Reference(Reference::Named(r)) = &dt
+            && let Some(ndt) = self.exporter.types.get(r)
+            && is_tauri_channel(ndt)
+        {
+            let message = match &r.inner {
+                NamedReferenceType::Reference { generics, .. } => generics.first(),
+                _ => None,
+            };
+            return Ok(json!({
+                "type": "string",
+                "x-channel": match message {
+                    Some((_, dt)) => self.schema(dt, &[])?,
+                    None => json!({}),
+                },
+            }));
+        }
+
+        self.schema(&dt, &[])
+    }
+
+    /// `generics` are the schemas of the type arguments of the generic type that is being inlined.
+    fn schema(&self, dt: &DataType, generics: &[(Generic, Value)]) -> Result<Value, Error> {
+        Ok(match dt {
+            DataType::Primitive(p) => primitive(p),
+            DataType::Generic(g) => generics
+                .iter()
+                .find(|(generic, _)| generic == g)
+                .map(|(_, schema)| schema.clone())
+                .unwrap_or_else(|| json!({})),
+            DataType::List(list) => {
+                let mut schema = json!({
+                    "type": "array",
+                    "items": self.schema(&list.ty, generics)?,
+                });
+                if let Some(length) = list.length {
+                    schema["minItems"] = length.into();
+                    schema["maxItems"] = length.into();
+                }
+                schema
+            }
+            // JSON object keys are always strings.
+            DataType::Map(map) => json!({
+                "type": "object",
+                "additionalProperties": self.schema(map.value_ty(), generics)?,
+            }),
+            DataType::Nullable(inner) => json!({
+                "anyOf": [self.schema(inner, generics)?, { "type": "null" }],
+            }),
+            DataType::Struct(strct) => self.fields(&strct.fields, generics)?,
+            DataType::Enum(enm) => {
+                let variants = enm
+                    .variants
+                    .iter()
+                    .filter(|(_, variant)| !variant.skip)
+                    .map(|(name, variant)| {
+                        let mut schema = match &variant.fields {
+                            Fields::Unit => json!({ "const": name }),
+                            fields => self.fields(fields, generics)?,
+                        };
+                        describe(&mut schema, &variant.docs, variant.deprecated.as_ref());
+                        Ok(schema)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                json!({ "anyOf": variants })
+            }
+            DataType::Tuple(tuple) => match tuple.elements.as_slice() {
+                [] => json!({ "type": "null" }),
+                elements => tuple_schema(
+                    elements
+                        .iter()
+                        .map(|dt| self.schema(dt, generics))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+            },
+            DataType::Intersection(parts) => json!({
+                "allOf": parts
+                    .iter()
+                    .map(|dt| self.schema(dt, generics))
+                    .collect::<Result<Vec<_>, _>>()?,
+            }),
+            DataType::Reference(Reference::Named(r)) => match &r.inner {
+                NamedReferenceType::Reference { generics: args, .. } => {
+                    let Some(ndt) = self.exporter.types.get(r) else {
+                        return Ok(json!({}));
+                    };
+
+                    if ndt.generics.is_empty() {
+                        json!({ "$ref": format!("#/components/schemas/{}", ndt.name) })
+                    } else {
+                        self.inline_generic(ndt, args, generics)?
+                    }
+                }
+                NamedReferenceType::Inline { dt, .. } => self.schema(dt, generics)?,
+                NamedReferenceType::Recursive(_) => json!({}),
+            },
+            DataType::Reference(r @ Reference::Opaque(opaque)) => self.opaque(r, opaque)?,
+        })
+    }
+
+    fn inline_generic(
+        &self,
+        ndt: &NamedDataType,
+        args: &[(Generic, DataType)],
+        generics: &[(Generic, Value)],
+    ) -> Result<Value, Error> {
+        let Some(ty) = &ndt.ty else {
+            return Ok(json!({}));
+        };
+
+        let args = ndt
+            .generics
+            .iter()
+            .map(|definition| {
+                let generic = definition.reference();
+                let schema = match args.iter().find(|(g, _)| *g == generic) {
+                    Some((_, dt)) => self.schema(dt, generics)?,
+                    None => json!({}),
+                };
+                Ok((generic, schema))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut schema = self.schema(ty, &args)?;
+        describe(&mut schema, &ndt.docs, ndt.deprecated.as_ref());
+        Ok(schema)
+    }
+
+    fn fields(&self, fields: &Fields, generics: &[(Generic, Value)]) -> Result<Value, Error> {
+        Ok(match fields {
+            Fields::Unit => json!({ "type": "null" }),
+            Fields::Unnamed(unnamed) => {
+                let fields = unnamed
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.ty.as_ref())
+                    .map(|dt| self.schema(dt, generics))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match (fields.as_slice(), unnamed.fields.len()) {
+                    ([field], 1) => field.clone(),
+                    _ => tuple_schema(fields),
+                }
+            }
+            Fields::Named(named) => {
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for (name, field) in &named.fields {
+                    let Some(dt) = &field.ty else {
+                        continue;
+                    };
+
+                    let mut schema = self.schema(dt, generics)?;
+                    describe(&mut schema, &field.docs, field.deprecated.as_ref());
+                    properties.insert(name.to_string(), schema);
+                    if !field.optional {
+                        required.push(name.to_string());
+                    }
+                }
+
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                })
+            }
+        })
+    }
+
+    fn opaque(&self, r: &Reference, opaque: &OpaqueReference) -> Result<Value, Error> {
+        let is = |dt: DataType| matches!(dt, DataType::Reference(Reference::Opaque(other)) if other == *opaque);
+        let mut types = Types::default();
+
+        Ok(
+            if is(<specta_typescript::Number as Type>::definition(&mut types)) {
+                // Only used for large integers, see `BigIntExport::Number`.
+                json!({ "type": "integer" })
+            } else if is(<specta_typescript::BigInt as Type>::definition(&mut types)) {
+                // Large integers are sent as strings by `BigIntExport::BigInt`.
+                json!({ "type": "string", "pattern": "^-?\\d+$" })
+            } else if is(<specta_typescript::Never as Type>::definition(&mut types)) {
+                json!({ "not": {} })
+            } else {
+                match self.exporter.reference(r)?.as_ref() {
+                    "string" => json!({ "type": "string" }),
+                    _ => json!({}),
+                }
+            },
+        )
+    }
+}
+
+fn primitive(p: &Primitive) -> Value {
+    use Primitive::*;
+
+    match p {
+        i8 | i16 | i32 | i64 | i128 | isize => json!({ "type": "integer" }),
+        u8 | u16 | u32 | u64 | u128 | usize => json!({ "type": "integer", "minimum": 0 }),
+        // `NaN` and `Infinity` are serialized as `null` by Serde JSON.
+        f16 | f32 | f64 | f128 => json!({ "type": ["number", "null"] }),
+        bool => json!({ "type": "boolean" }),
+        char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        str => json!({ "type": "string" }),
+    }
+}
+
+fn tuple_schema(items: Vec<Value>) -> Value {
+    let length = items.len();
+    json!({
+        "type": "array",
+        "items": items,
+        "minItems": length,
+        "maxItems": length,
+    })
+}
+
+/// Add the doc comments and deprecation to a schema or method.
+fn describe(value: &mut Value, docs: &str, deprecated: Option<&Deprecated>) {
+    let docs = docs.trim();
+    if docs.is_empty() && deprecated.is_none() {
+        return;
+    }
+
+    // Siblings of `$ref` are ignored, so the reference is wrapped.
+    if value.get("$ref").is_some() {
+        *value = json!({ "allOf": [value.take()] });
+    }
+    if !docs.is_empty() {
+        value["description"] = docs.into();
+    }
+    if deprecated.is_some() {
+        value["deprecated"] = true.into();
+    }
+}
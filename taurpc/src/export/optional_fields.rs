@@ -0,0 +1,145 @@
+use specta::datatype::{
+    DataType, Enum, Field, Fields, NamedDataType, NamedReferenceType, Reference, Variant,
+};
+use specta_typescript::define;
+
+/// How fields of type `Option<T>` are rendered, see [`Exporter::optional_fields`](super::Exporter::optional_fields).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OptionalFields {
+    /// `field: T | null`, `None` is serialized as `null`.
+    #[default]
+    Nullable,
+    /// `field?: T`, for the fields that are skipped with `#[serde(skip_serializing_if = "Option::is_none")]`.
+    Optional,
+    /// `field: T | undefined`, for the fields that are skipped with `#[serde(skip_serializing_if = "Option::is_none")]`.
+    Undefined,
+}
+
+/// Marks the `Option<T>` fields that are skipped when they are `None`, only these fields are rewritten.
+const SKIP_NONE: &str = "taurpc:field:skip_none";
+
+/// The attribute that is added by `#[serde(skip_serializing_if = "...")]`.
+const SKIP_SERIALIZING_IF: &str = "serde:field:skip_serializing_if";
+
+impl OptionalFields {
+    /// Mark the `Option<T>` fields of `ndt` that are skipped with `#[serde(skip_serializing_if = "Option::is_none")]`.
+    /// This has to be done before the types are split by `specta_serde`, which removes the attribute.
+    pub(super) fn mark_skipped(self, mut ndt: NamedDataType) -> NamedDataType {
+        if self != Self::Nullable
+            && let Some(dt) = &mut ndt.ty
+        {
+            for_each_named_field(dt, &mut |field| {
+                let skips_none = field
+                    .attributes
+                    .get_named_as::<String>(SKIP_SERIALIZING_IF)
+                    .is_some_and(|path| is_option_is_none(path));
+                if skips_none && matches!(field.ty, Some(DataType::Nullable(_))) {
+                    field.attributes.insert(SKIP_NONE, true);
+                }
+            });
+        }
+        ndt
+    }
+
+    /// Rewrite the marked `Option<T>` fields of the structs and enum variants in `dt`.
+    pub(super) fn apply(self, dt: &mut DataType) {
+        if self == Self::Nullable {
+            return;
+        }
+
+        for_each_named_field(dt, &mut |field| self.apply_field(field));
+    }
+
+    fn apply_field(self, field: &mut Field) {
+        // `None` is serialized as `null` when the field isn't skipped.
+        if !field.attributes.contains_key(SKIP_NONE) {
+            return;
+        }
+        let Some(DataType::Nullable(inner)) = &field.ty else {
+            return;
+        };
+        let inner = (**inner).clone();
+        match self {
+            Self::Nullable => {}
+            Self::Optional => {
+                field.optional = true;
+                field.ty = Some(inner);
+            }
+            Self::Undefined => {
+                // An untagged union of the type and `undefined`.
+                let mut union = Enum::default();
+                for dt in [inner, DataType::Reference(define("undefined"))] {
+                    union
+                        .variants
+                        .push(("".into(), Variant::unnamed().field(Field::new(dt)).build()));
+                }
+                field.optional = false;
+                field.ty = Some(DataType::Enum(union));
+            }
+        }
+    }
+}
+
+/// Whether the path of `skip_serializing_if` is `Option::is_none`.
+fn is_option_is_none(path: &str) -> bool {
+    let path = path.trim().trim_start_matches("::");
+    [
+        "Option::is_none",
+        "option::Option::is_none",
+        "core::option::Option::is_none",
+        "std::option::Option::is_none",
+    ]
+    .contains(&path)
+}
+
+/// Call `f` for the named fields of the structs and enum variants in `dt`, after their types are visited.
+fn for_each_named_field(dt: &mut DataType, f: &mut impl FnMut(&mut Field)) {
+    match dt {
+        DataType::Primitive(_) | DataType::Generic(_) => {}
+        DataType::List(list) => for_each_named_field(&mut list.ty, f),
+        DataType::Map(map) => {
+            for_each_named_field(map.key_ty_mut(), f);
+            for_each_named_field(map.value_ty_mut(), f);
+        }
+        DataType::Struct(strct) => fields(&mut strct.fields, f),
+        DataType::Enum(enm) => {
+            for (_, variant) in &mut enm.variants {
+                fields(&mut variant.fields, f);
+            }
+        }
+        DataType::Tuple(tuple) => tuple
+            .elements
+            .iter_mut()
+            .for_each(|dt| for_each_named_field(dt, f)),
+        DataType::Nullable(inner) => for_each_named_field(inner, f),
+        DataType::Intersection(parts) => {
+            parts.iter_mut().for_each(|dt| for_each_named_field(dt, f))
+        }
+        DataType::Reference(Reference::Named(r)) => match &mut r.inner {
+            NamedReferenceType::Reference { generics, .. } => generics
+                .iter_mut()
+                .for_each(|(_, dt)| for_each_named_field(dt, f)),
+            NamedReferenceType::Inline { dt, .. } => for_each_named_field(dt, f),
+            NamedReferenceType::Recursive(_) => {}
+        },
+        DataType::Reference(Reference::Opaque(_)) => {}
+    }
+}
+
+fn fields(fields: &mut Fields, f: &mut impl FnMut(&mut Field)) {
+    match fields {
+        Fields::Unit => {}
+        // Tuple elements can't be optional, only their types are visited.
+        Fields::Unnamed(unnamed) => unnamed
+            .fields
+            .iter_mut()
+            .filter_map(|field| field.ty.as_mut())
+            .for_each(|dt| for_each_named_field(dt, f)),
+        Fields::Named(named) => named.fields.iter_mut().for_each(|(_, field)| {
+            if let Some(dt) = &mut field.ty {
+                for_each_named_field(dt, f);
+            }
+            f(field);
+        }),
+    }
+}
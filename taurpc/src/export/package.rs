@@ -0,0 +1,51 @@
+use specta_typescript::Error;
+use std::path::Path;
+
+/// The files of the `.taurpc` package generated by [`Exporter::export_package`](super::Exporter::export_package).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PackageFormat {
+    /// An `index.ts` file, the bundler of the frontend has to compile TypeScript in `node_modules`.
+    #[default]
+    Typescript,
+    /// An `index.js` file with an `index.d.ts` declaration file.
+    ///
+    /// This doesn't support [`ClientRuntime::Inline`](super::ClientRuntime::Inline).
+    Javascript,
+}
+
+pub(super) fn write_package_json(dir: &Path, format: PackageFormat) -> Result<(), Error> {
+    let (types, main) = match format {
+        PackageFormat::Typescript => ("./index.ts", "./index.ts"),
+        PackageFormat::Javascript => ("./index.d.ts", "./index.js"),
+    };
+
+    let package_json = format!(
+        r#"{{
+    "name": ".taurpc",
+    "private": true,
+    "type": "module",
+    "main": "{main}",
+    "types": "{types}",
+    "exports": {{
+        ".": {{
+            "types": "{types}",
+            "default": "{main}"
+        }}
+    }}
+}}
+"#
+    );
+
+    write_file(&dir.join("package.json"), package_json)
+}
+
+pub(super) fn write_file(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            Error::framework(format!("Failed to create '{}'", parent.display()), err)
+        })?;
+    }
+
+    std::fs::write(path, contents)
+        .map_err(|err| Error::framework(format!("Failed to write '{}'", path.display()), err))
+}
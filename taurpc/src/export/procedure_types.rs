@@ -0,0 +1,113 @@
+use heck::ToUpperCamelCase;
+use specta::{
+    Types,
+    datatype::{DataType, Function, NamedDataType},
+};
+use specta_typescript::{Error, FrameworkExporter, define};
+use std::collections::BTreeMap;
+
+use super::{Output, SpectaFormat, jsdoc, render_args, render_output, takes_object_args};
+
+/// Render the named input and output types of the procedures, the `ProcedurePath` union
+/// and the `AllEvents` union, see [`Exporter::procedure_types`](super::Exporter::procedure_types).
+pub(super) fn generate_procedure_types(
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    object_args: &BTreeMap<String, Vec<String>>,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+    output: Output,
+) -> Result<String, Error> {
+    let mut types = Vec::new();
+    let mut procedure_paths = Vec::new();
+    let mut all_events = Vec::new();
+
+    // A trait that is mounted at more than one path with `Router::merge_at` is named after the path.
+    let mut trait_paths = BTreeMap::<&str, usize>::new();
+    for path_functions in functions.values() {
+        if let Some((trait_name, _)) = path_functions
+            .first()
+            .and_then(|f| f.name().split_once("_taurpc_fn__"))
+        {
+            *trait_paths.entry(trait_name).or_default() += 1;
+        }
+    }
+
+    for (path, path_functions) in functions {
+        let mut path_functions: Vec<_> = path_functions
+            .iter()
+            .map(|f| (f.name().split_once("_taurpc_fn__").unwrap(), f))
+            .collect();
+        path_functions.sort_by(|a, b| a.0.1.cmp(b.0.1));
+
+        for ((trait_name, name), function) in path_functions {
+            let procedure_path = if path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{path}.{name}")
+            };
+            let prefix = match trait_paths.get(trait_name) {
+                Some(&count) if count > 1 => path.replace('.', "_").to_upper_camel_case(),
+                _ => trait_name.to_string(),
+            };
+            let type_name = format!("{prefix}_{}", name.to_upper_camel_case());
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+
+            let object_args = takes_object_args(object_args, path, function);
+            let args = render_args(function, object_args, is_event, exporter, format)?;
+            types.push(named_type(
+                format!("{type_name}Input"),
+                format!("The arguments of `{procedure_path}`."),
+                format!("[{}]", args.join(", ")),
+            ));
+
+            let literal = serde_json::to_string(&procedure_path).unwrap();
+            if is_event {
+                all_events.push(format!("{{ event: {literal}; payload: {type_name}Input }}"));
+            } else {
+                types.push(named_type(
+                    format!("{type_name}Output"),
+                    format!("The output of `{procedure_path}`."),
+                    render_output(function, exporter, format)?,
+                ));
+                procedure_paths.push(literal);
+            }
+        }
+    }
+
+    types.push(named_type(
+        "ProcedurePath".into(),
+        "The paths of all procedures that can be called.".into(),
+        union(procedure_paths),
+    ));
+    types.push(named_type(
+        "AllEvents".into(),
+        "All events, tagged by their path.".into(),
+        union(all_events),
+    ));
+
+    let mut out = String::from("\n");
+    for ndt in &types {
+        out.push_str(&match output {
+            Output::Javascript => jsdoc::typedef(ndt, exporter)?,
+            _ => exporter.export(std::iter::once(ndt), "")?,
+        });
+    }
+    Ok(out)
+}
+
+fn named_type(name: String, docs: String, ts_type: String) -> NamedDataType {
+    NamedDataType::new(name, &mut Types::default(), |_, ndt| {
+        ndt.docs = docs.into();
+        ndt.ty = Some(DataType::Reference(define(ts_type)));
+    })
+}
+
+fn union(variants: Vec<String>) -> String {
+    if variants.is_empty() {
+        return "never".into();
+    }
+    variants.join(" | ")
+}
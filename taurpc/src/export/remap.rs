@@ -0,0 +1,62 @@
+use specta::{Type, Types, datatype::DataType};
+use specta_typescript::define;
+
+/// Built-in remapping rules for types from `std` and common crates, see [`Exporter::rule_set`](super::Exporter::rule_set).
+///
+/// These types are all serialized as strings, the rules export them as branded strings
+/// (e.g. `string & { readonly __brand: "Uuid" }`) so they can't be mixed up with other strings on the frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RuleSet {
+    /// `PathBuf` and `Path`.
+    Paths,
+    /// `DateTime<Utc>`, `DateTime<Local>`, `DateTime<FixedOffset>`, `NaiveDateTime`, `NaiveDate` and `NaiveTime`.
+    #[cfg(feature = "chrono")]
+    Chrono,
+    /// `Uuid`.
+    #[cfg(feature = "uuid")]
+    Uuid,
+    /// `Url`.
+    #[cfg(feature = "url")]
+    Url,
+}
+
+impl RuleSet {
+    pub(super) fn rules(&self) -> Vec<(DataType, DataType)> {
+        match self {
+            Self::Paths => vec![
+                rule::<std::path::PathBuf>("PathBuf"),
+                rule::<std::path::Path>("PathBuf"),
+            ],
+            #[cfg(feature = "chrono")]
+            Self::Chrono => vec![
+                rule::<chrono::DateTime<chrono::Utc>>("DateTime"),
+                rule::<chrono::DateTime<chrono::Local>>("DateTime"),
+                rule::<chrono::DateTime<chrono::FixedOffset>>("DateTime"),
+                rule::<chrono::NaiveDateTime>("NaiveDateTime"),
+                rule::<chrono::NaiveDate>("NaiveDate"),
+                rule::<chrono::NaiveTime>("NaiveTime"),
+            ],
+            #[cfg(feature = "uuid")]
+            Self::Uuid => vec![rule::<uuid::Uuid>("Uuid")],
+            #[cfg(feature = "url")]
+            Self::Url => vec![rule::<url::Url>("Url")],
+        }
+    }
+}
+
+/// Remap `T` to a string with the given brand.
+fn rule<T: Type + ?Sized>(brand: &str) -> (DataType, DataType) {
+    (
+        definition::<T>(),
+        ts_type(format!("string & {{ readonly __brand: \"{brand}\" }}")),
+    )
+}
+
+pub(super) fn definition<T: Type + ?Sized>() -> DataType {
+    T::definition(&mut Types::default())
+}
+
+pub(super) fn ts_type(ts_type: impl Into<std::borrow::Cow<'static, str>>) -> DataType {
+    DataType::Reference(define(ts_type))
+}
@@ -0,0 +1,131 @@
+use heck::ToLowerCamelCase;
+use specta::datatype::Function;
+use std::collections::BTreeMap;
+use taurpc_rename::RenameRule;
+
+use crate::PathDocs;
+
+/// Replace the names of the arguments with the names used in the bindings, the docs of the arguments are moved along.
+///
+/// Without a rule, arguments that are renamed with `#[taurpc(rename)]` or `rename_all` keep the key sent to the
+/// backend, the other arguments are converted to camelCase.
+pub(super) fn rename_args(
+    functions: &mut BTreeMap<String, Vec<Function>>,
+    docs: &mut BTreeMap<String, PathDocs>,
+    args_map: &BTreeMap<String, String>,
+    rule: Option<RenameRule>,
+) {
+    for (path, path_functions) in functions {
+        let keys: BTreeMap<String, Vec<String>> = args_map
+            .get(path)
+            .and_then(|args| serde_json::from_str(args).ok())
+            .unwrap_or_default();
+
+        for function in path_functions {
+            let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+            let keys = keys.get(name);
+            let arg_docs = docs.get_mut(path).and_then(|docs| docs.args.get_mut(name));
+            let mut renamed_docs = BTreeMap::new();
+
+            for (idx, (arg_name, _)) in function.args.iter_mut().enumerate() {
+                let key = keys.and_then(|keys| keys.get(idx)).map(String::as_str);
+                let renamed = match (rule, key) {
+                    (Some(rule), Some(key)) => rule.apply(key),
+                    (Some(rule), None) => rule.apply(arg_name),
+                    (None, Some(key)) if key != arg_name.as_ref() => key.to_string(),
+                    (None, _) => arg_name.to_lower_camel_case(),
+                };
+
+                // The docs are keyed by the key of the argument.
+                if let Some(doc) = arg_docs
+                    .as_ref()
+                    .and_then(|arg_docs| arg_docs.get(key.unwrap_or(arg_name)))
+                {
+                    renamed_docs.insert(renamed.clone(), doc.clone());
+                }
+                *arg_name = renamed.into();
+            }
+
+            if let Some(arg_docs) = arg_docs {
+                *arg_docs = renamed_docs;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specta::Types;
+
+    fn functions() -> BTreeMap<String, Vec<Function>> {
+        #[specta::specta]
+        #[allow(unused_variables)]
+        fn search(user_id: u32, limit: Option<u32>) {}
+
+        let mut function = specta::function::fn_datatype!(search)(&mut Types::default());
+        function.name = "Api_taurpc_fn__search".into();
+        BTreeMap::from([("api".to_string(), vec![function])])
+    }
+
+    fn docs() -> BTreeMap<String, PathDocs> {
+        let args = BTreeMap::from([
+            ("user_id".to_string(), "The user".to_string()),
+            ("maxResults".to_string(), "The limit".to_string()),
+        ]);
+        BTreeMap::from([(
+            "api".to_string(),
+            PathDocs {
+                docs: String::new(),
+                args: BTreeMap::from([("search".to_string(), args)]),
+            },
+        )])
+    }
+
+    fn args_map() -> BTreeMap<String, String> {
+        BTreeMap::from([(
+            "api".to_string(),
+            r#"{"search":["user_id","maxResults"]}"#.to_string(),
+        )])
+    }
+
+    fn renamed(
+        args_map: &BTreeMap<String, String>,
+        rule: Option<RenameRule>,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut functions = functions();
+        let mut docs = docs();
+        rename_args(&mut functions, &mut docs, args_map, rule);
+
+        let names = functions["api"][0]
+            .args()
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let doc_keys = docs["api"].args["search"].keys().cloned().collect();
+        (names, doc_keys)
+    }
+
+    #[test]
+    fn keep_renamed_args() {
+        let (names, doc_keys) = renamed(&args_map(), None);
+        assert_eq!(names, ["userId", "maxResults"]);
+        assert_eq!(doc_keys, ["maxResults", "userId"]);
+    }
+
+    #[test]
+    fn rename_all_args() {
+        let (names, doc_keys) = renamed(&args_map(), Some(RenameRule::SnakeCase));
+        assert_eq!(names, ["user_id", "max_results"]);
+        assert_eq!(doc_keys, ["max_results", "user_id"]);
+    }
+
+    #[test]
+    fn without_keys() {
+        let (names, _) = renamed(&BTreeMap::new(), None);
+        assert_eq!(names, ["userId", "limit"]);
+
+        let (names, _) = renamed(&BTreeMap::new(), Some(RenameRule::PascalCase));
+        assert_eq!(names, ["UserId", "Limit"]);
+    }
+}
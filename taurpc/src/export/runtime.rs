@@ -0,0 +1,91 @@
+use specta_typescript::Error;
+use std::borrow::Cow;
+
+use super::Output;
+
+/// The TypeScript runtime, this is also the source of the `taurpc` npm package.
+const RUNTIME: &str = include_str!("../runtime.ts");
+
+const EXPORTS_START: &str = "// #region exports";
+const EXPORTS_END: &str = "// #endregion";
+
+/// Where the generated bindings get the frontend runtime from, see [`Exporter::runtime`](super::Exporter::runtime).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientRuntime {
+    /// Import the runtime from a package, `taurpc` by default.
+    ///
+    /// The version of the package has to match the version of this crate.
+    Import(Cow<'static, str>),
+    /// Include the runtime in the generated bindings, so the `taurpc` npm package is not needed.
+    ///
+    /// This always matches the version of this crate, only `@tauri-apps/api` is required.
+    Inline,
+}
+
+impl Default for ClientRuntime {
+    fn default() -> Self {
+        Self::Import(Cow::Borrowed("taurpc"))
+    }
+}
+
+impl ClientRuntime {
+    /// Generate the code that provides `createProxy` and `InferCommandOutput` to the bindings.
+    pub(super) fn generate(&self, output: Output) -> Result<String, Error> {
+        Ok(match (self, output) {
+            (Self::Import(specifier), Output::Javascript) => {
+                format!("\nimport {{ createTauRPCProxy as createProxy }} from '{specifier}'\n")
+            }
+            (Self::Import(specifier), _) => format!(
+                "\nimport {{ createTauRPCProxy as createProxy, type InferCommandOutput }} from '{specifier}'\n"
+            ),
+            (Self::Inline, Output::Typescript) => inline(),
+            (Self::Inline, _) => {
+                return Err(Error::framework(
+                    "failed to generate the runtime",
+                    "the runtime can only be inlined in TypeScript bindings",
+                ));
+            }
+        })
+    }
+
+    pub(super) fn reexports_types(&self) -> bool {
+        matches!(self, Self::Import(_))
+    }
+}
+
+/// Inline the runtime, the exports region is kept at the top level and everything else
+/// is scoped to a function so it can't conflict with the exported types.
+fn inline() -> String {
+    let mut imports = String::new();
+    let mut exports = String::new();
+    let mut body = String::new();
+    let mut in_exports = false;
+
+    for line in RUNTIME.lines() {
+        match line {
+            line if line.starts_with(EXPORTS_START) => in_exports = true,
+            line if line.starts_with(EXPORTS_END) => in_exports = false,
+            line if in_exports => {
+                exports.push_str(line);
+                exports.push('\n');
+            }
+            line if line.starts_with("import ") => {
+                imports.push_str(line);
+                imports.push('\n');
+            }
+            // The exports of the npm package.
+            line if line.starts_with("export ") => {}
+            "" => body.push('\n'),
+            line => {
+                body.push_str("  ");
+                body.push_str(line);
+                body.push('\n');
+            }
+        }
+    }
+
+    format!(
+        "\n{imports}\n{exports}\nconst createProxy = (() => {{\n{}\n\n  return createTauRPCProxy\n}})()\n\n",
+        body.trim_matches('\n')
+    )
+}
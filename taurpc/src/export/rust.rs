@@ -0,0 +1,482 @@
+use heck::ToSnakeCase;
+use specta::{
+    Types,
+    datatype::{DataType, Function, NamedReferenceType, Primitive, Reference},
+};
+use std::collections::BTreeMap;
+
+use super::{FRAMEWORK_HEADER, extract_std_result, is_tauri_channel};
+
+static BOILERPLATE_RUST_RUNTIME: &str = r#"
+#![allow(unused, clippy::all)]
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::{JsCast, JsValue, prelude::*};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
+    async fn tauri_invoke(command: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = ["window", "__TAURI__", "event"], js_name = listen)]
+    async fn tauri_listen(
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = Channel)]
+    type TauriChannel;
+
+    #[wasm_bindgen(constructor, js_namespace = ["window", "__TAURI__", "core"], js_class = "Channel")]
+    fn new() -> TauriChannel;
+
+    #[wasm_bindgen(method, setter, js_class = "Channel", js_name = onmessage)]
+    fn set_onmessage(this: &TauriChannel, handler: &Closure<dyn FnMut(JsValue)>);
+}
+
+/// The errors returned by the procedures, `E` is the error type of procedures that return a `Result`.
+#[derive(Debug)]
+pub enum Error<E = std::convert::Infallible> {
+    /// The error returned by the procedure.
+    Procedure(E),
+    /// The command failed, e.g. because the backend couldn't deserialize the arguments.
+    Invoke(JsValue),
+    /// The arguments or the response couldn't be converted.
+    Serde(serde_wasm_bindgen::Error),
+}
+
+impl<E> Error<E> {
+    fn invoke(error: JsValue) -> Self {
+        Self::Invoke(error)
+    }
+}
+
+impl<E: DeserializeOwned> Error<E> {
+    fn procedure(error: JsValue) -> Self {
+        match serde_wasm_bindgen::from_value(error.clone()) {
+            Ok(error) => Self::Procedure(error),
+            Err(_) => Self::Invoke(error),
+        }
+    }
+}
+
+impl<E> From<serde_wasm_bindgen::Error> for Error<E> {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        Self::Serde(error)
+    }
+}
+
+/// Listens for an event until it is dropped.
+pub struct Unlisten {
+    unlisten: js_sys::Function,
+    _handler: Closure<dyn FnMut(JsValue)>,
+}
+
+impl Unlisten {
+    /// Keep listening for the rest of the lifetime of the page.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Unlisten {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&JsValue::NULL);
+    }
+}
+
+fn serialize<T: Serialize, E>(value: &T) -> Result<JsValue, Error<E>> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+async fn call<T: DeserializeOwned, E>(
+    command: &str,
+    args: Vec<(&str, JsValue)>,
+    error: fn(JsValue) -> Error<E>,
+) -> Result<T, Error<E>> {
+    let object = js_sys::Object::new();
+    for (name, value) in args {
+        js_sys::Reflect::set(&object, &JsValue::from_str(name), &value).map_err(Error::Invoke)?;
+    }
+
+    let response = tauri_invoke(command, object.into()).await.map_err(error)?;
+    Ok(serde_wasm_bindgen::from_value(response)?)
+}
+
+fn channel<T: DeserializeOwned + 'static>(mut listener: impl FnMut(T) + 'static) -> JsValue {
+    let channel = TauriChannel::new();
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |message: JsValue| {
+        if let Ok(message) = serde_wasm_bindgen::from_value(message) {
+            listener(message);
+        }
+    });
+    channel.set_onmessage(&handler);
+    // Messages can still be sent after the command returned.
+    handler.forget();
+    channel.into()
+}
+
+async fn listen<T: DeserializeOwned + 'static>(
+    event_name: &'static str,
+    mut listener: impl FnMut(T) + 'static,
+) -> Result<Unlisten, Error> {
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+        let get = |value: &JsValue, key: &str| js_sys::Reflect::get(value, &JsValue::from_str(key));
+        let Ok(payload) = get(&event, "payload") else {
+            return;
+        };
+        if get(&payload, "event_name")
+            .ok()
+            .and_then(|name| name.as_string())
+            .as_deref()
+            != Some(event_name)
+        {
+            return;
+        }
+
+        let input = get(&payload, "event").and_then(|event| get(&event, "input_type"));
+        if let Some(input) = input
+            .ok()
+            .and_then(|input| serde_wasm_bindgen::from_value(input).ok())
+        {
+            listener(input);
+        }
+    });
+
+    let unlisten = tauri_listen("TauRpc_event", &handler)
+        .await
+        .map_err(Error::Invoke)?;
+    Ok(Unlisten {
+        unlisten: unlisten.unchecked_into(),
+        _handler: handler,
+    })
+}
+"#;
+
+/// Render a Rust module that calls the procedures from a WASM frontend.
+pub(super) fn generate_client(
+    types: &Types,
+    functions: &BTreeMap<String, Vec<Function>>,
+    args_map: &BTreeMap<String, String>,
+    events: &BTreeMap<String, Vec<String>>,
+    type_paths: &[(String, String)],
+) -> String {
+    let renderer = TypeRenderer { types, type_paths };
+
+    let mut root = Module::default();
+    for (path, path_functions) in functions {
+        let arg_names: BTreeMap<String, Vec<String>> = args_map
+            .get(path)
+            .and_then(|args| serde_json::from_str(args).ok())
+            .unwrap_or_default();
+
+        let mut path_functions: Vec<_> = path_functions
+            .iter()
+            .map(|f| (f.name().split_once("_taurpc_fn__").unwrap().1, f))
+            .collect();
+        path_functions.sort_by(|a, b| a.0.cmp(b.0));
+
+        let module = path
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .fold(&mut root, |module, segment| {
+                module.children.entry(segment.to_string()).or_default()
+            });
+        for (name, function) in path_functions {
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+            let arg_names = arg_names.get(name).cloned().unwrap_or_default();
+            let procedure = if path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{path}.{name}")
+            };
+
+            module.items.push(if is_event {
+                renderer.listener(&procedure, name, function)
+            } else {
+                renderer.procedure(&procedure, name, function, &arg_names)
+            });
+        }
+    }
+
+    let mut out = format!("{FRAMEWORK_HEADER}\n{BOILERPLATE_RUST_RUNTIME}");
+    root.render(&mut out, "");
+    out
+}
+
+#[derive(Default)]
+struct Module {
+    items: Vec<String>,
+    children: BTreeMap<String, Module>,
+}
+
+impl Module {
+    fn render(&self, out: &mut String, indent: &str) {
+        for item in &self.items {
+            out.push('\n');
+            for line in item.lines() {
+                if !line.is_empty() {
+                    out.push_str(indent);
+                }
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+
+        for (name, module) in &self.children {
+            out.push_str(&format!(
+                "\n{indent}pub mod {} {{\n{indent}    use super::*;\n",
+                ident(name)
+            ));
+            module.render(out, &format!("{indent}    "));
+            out.push_str(&format!("{indent}}}\n"));
+        }
+    }
+}
+
+struct TypeRenderer<'a> {
+    types: &'a Types,
+    type_paths: &'a [(String, String)],
+}
+
+impl TypeRenderer<'_> {
+    fn procedure(
+        &self,
+        procedure: &str,
+        name: &str,
+        function: &Function,
+        arg_names: &[String],
+    ) -> String {
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for (idx, (arg_name, dt)) in function.args().iter().enumerate() {
+            let key = arg_names
+                .get(idx)
+                .map(String::as_str)
+                .unwrap_or(arg_name.as_ref());
+            let param = ident(key);
+
+            if let Some(message) = self.channel_message(dt) {
+                params.push(format!("{param}: impl FnMut({message}) + 'static"));
+                args.push(format!("({key:?}, channel({param}))"));
+            } else {
+                params.push(format!("{param}: {}", self.render(dt)));
+                args.push(format!("({key:?}, serialize(&{param})?)"));
+            }
+        }
+
+        let (output, error) = match function.result() {
+            Some(result) => match extract_std_result(result, self.types) {
+                Some((ok, err)) => (self.render(ok), Some(self.render(err))),
+                None => (self.render(result), None),
+            },
+            None => ("()".to_string(), None),
+        };
+        let (error_type, error_fn) = match &error {
+            Some(error) => (format!("Error<{error}>"), "Error::procedure"),
+            None => ("Error".to_string(), "Error::invoke"),
+        };
+
+        format!(
+            "{}pub async fn {}({}) -> Result<{output}, {error_type}> {{\n    call(\"TauRPC__{procedure}\", vec![{}], {error_fn}).await\n}}\n",
+            docs(&function.docs),
+            ident(name),
+            params.join(", "),
+            args.join(", "),
+        )
+    }
+
+    fn listener(&self, procedure: &str, name: &str, function: &Function) -> String {
+        let arg_types: Vec<_> = function
+            .args()
+            .iter()
+            .map(|(_, dt)| self.render(dt))
+            .collect();
+
+        // The arguments of events are sent as a tuple, unless there's a single argument.
+        let handler = match arg_types.as_slice() {
+            [_] => "listener".to_string(),
+            args => {
+                let names: Vec<_> = (0..args.len()).map(|idx| format!("arg{idx}")).collect();
+                format!(
+                    "move |({}): ({})| listener({})",
+                    names.join(", "),
+                    args.join(", "),
+                    names.join(", ")
+                )
+            }
+        };
+
+        format!(
+            "{}pub async fn {}(mut listener: impl FnMut({}) + 'static) -> Result<Unlisten, Error> {{\n    listen({procedure:?}, {handler}).await\n}}\n",
+            docs(&function.docs),
+            ident(&format!("on_{name}")),
+            arg_types.join(", "),
+        )
+    }
+
+    fn channel_message(&self, dt: &DataType) -> Option<String> {
+        if let DataType::Reference(Reference::Named(r)) = dt
+            && let Some(ndt) = self.types.get(r)
+            && is_tauri_channel(ndt)
+        {
+            let message = match &r.inner {
+                NamedReferenceType::Reference { generics, .. } => generics.first(),
+                _ => None,
+            };
+            return Some(
+                message
+                    .map(|(_, dt)| self.render(dt))
+                    .unwrap_or_else(|| UNKNOWN.to_string()),
+            );
+        }
+
+        None
+    }
+
+    fn render(&self, dt: &DataType) -> String {
+        match dt {
+            DataType::Primitive(p) => primitive(p).to_string(),
+            DataType::List(list) => match list.length {
+                Some(length) => format!("[{}; {length}]", self.render(&list.ty)),
+                None => format!("Vec<{}>", self.render(&list.ty)),
+            },
+            DataType::Map(map) => format!(
+                "::std::collections::HashMap<{}, {}>",
+                self.render(map.key_ty()),
+                self.render(map.value_ty())
+            ),
+            DataType::Nullable(inner) => format!("Option<{}>", self.render(inner)),
+            DataType::Tuple(tuple) => match tuple.elements.as_slice() {
+                [] => "()".to_string(),
+                [element] => format!("({},)", self.render(element)),
+                elements => format!(
+                    "({})",
+                    elements
+                        .iter()
+                        .map(|dt| self.render(dt))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            DataType::Generic(g) => g.name().to_string(),
+            DataType::Reference(Reference::Named(r)) => match &r.inner {
+                NamedReferenceType::Reference { generics, .. } => {
+                    let Some(ndt) = self.types.get(r) else {
+                        return UNKNOWN.to_string();
+                    };
+
+                    let mut path = self.type_path(&ndt.module_path, &ndt.name);
+                    if !ndt.generics.is_empty() {
+                        let generics = ndt
+                            .generics
+                            .iter()
+                            .map(|definition| {
+                                generics
+                                    .iter()
+                                    .find(|(generic, _)| *generic == definition.reference())
+                                    .map(|(_, dt)| self.render(dt))
+                                    .unwrap_or_else(|| UNKNOWN.to_string())
+                            })
+                            .collect::<Vec<_>>();
+                        path.push_str(&format!("<{}>", generics.join(", ")));
+                    }
+                    path
+                }
+                // Types like `PathBuf` or `chrono::DateTime` are inlined, they are used with their serialized type.
+                NamedReferenceType::Inline { dt, .. } => self.render(dt),
+                NamedReferenceType::Recursive(_) => UNKNOWN.to_string(),
+            },
+            // Anonymous structs and enums can't be named.
+            _ => UNKNOWN.to_string(),
+        }
+    }
+
+    fn type_path(&self, module_path: &str, name: &str) -> String {
+        for (from, to) in self.type_paths {
+            if let Some(rest) = module_path.strip_prefix(from.as_str())
+                && (rest.is_empty() || rest.starts_with("::"))
+            {
+                return format!("{to}{rest}::{name}");
+            }
+        }
+
+        if module_path.is_empty() {
+            name.to_string()
+        } else {
+            format!("::{module_path}::{name}")
+        }
+    }
+}
+
+/// Used for types that can't be named in the client.
+const UNKNOWN: &str = "::serde_json::Value";
+
+fn primitive(p: &Primitive) -> &'static str {
+    use Primitive::*;
+
+    match p {
+        i8 => "i8",
+        i16 => "i16",
+        i32 => "i32",
+        i64 => "i64",
+        i128 => "i128",
+        isize => "isize",
+        u8 => "u8",
+        u16 => "u16",
+        u32 => "u32",
+        u64 => "u64",
+        u128 => "u128",
+        usize => "usize",
+        f16 | f32 => "f32",
+        f64 | f128 => "f64",
+        bool => "bool",
+        char => "char",
+        str => "String",
+    }
+}
+
+fn docs(docs: &str) -> String {
+    docs.lines()
+        .map(|line| match line {
+            "" => "///\n".to_string(),
+            line if line.starts_with(' ') => format!("///{line}\n"),
+            line => format!("/// {line}\n"),
+        })
+        .collect()
+}
+
+/// Convert a procedure or path name to a Rust identifier.
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+        "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "return", "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+
+    // Procedures and arguments can be renamed with `rename_all`, Rust items are always snake_case.
+    let mut ident: String = name
+        .to_snake_case()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+
+    match ident.as_str() {
+        "self" | "Self" | "super" | "crate" => format!("{ident}_"),
+        ident if KEYWORDS.contains(&ident) => format!("r#{ident}"),
+        _ => ident,
+    }
+}
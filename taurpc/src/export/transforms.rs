@@ -0,0 +1,417 @@
+use serde_json::{Map, Value, json};
+use specta::{
+    Type, Types,
+    datatype::{
+        DataType, Fields, Function, Generic, List, NamedReferenceType, Primitive, Reference, Tuple,
+    },
+};
+use specta_serde::Phase;
+use specta_typescript::{Branded, define};
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{SpectaFormat, args_phase, extract_std_result, is_tauri_channel};
+
+/// The brand of the opaque type that replaces maps when [`Revive::Maps`] is enabled,
+/// it is rendered as `Map<K, V>`.
+pub(super) const MAP_BRAND: &str = "TauRPC.Map";
+
+/// Values that are converted by the generated client, see [`Exporter::revive`](super::Exporter::revive).
+///
+/// The exported types are changed to match, arguments are converted back to JSON before they are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Revive {
+    /// `chrono::DateTime` as `Date`.
+    #[cfg(feature = "chrono")]
+    Dates,
+    /// `Vec<u8>` as `Uint8Array`.
+    Bytes,
+    /// `HashMap`, `BTreeMap` and other maps as `Map<K, V>`.
+    Maps,
+}
+
+impl Revive {
+    pub(super) fn rules(&self) -> Vec<(DataType, DataType)> {
+        match self {
+            #[cfg(feature = "chrono")]
+            Self::Dates => vec![
+                (
+                    super::remap::definition::<chrono::DateTime<chrono::Utc>>(),
+                    date(),
+                ),
+                (
+                    super::remap::definition::<chrono::DateTime<chrono::Local>>(),
+                    date(),
+                ),
+                (
+                    super::remap::definition::<chrono::DateTime<chrono::FixedOffset>>(),
+                    date(),
+                ),
+            ],
+            Self::Bytes => vec![(DataType::List(List::new(Primitive::u8.into())), bytes())],
+            Self::Maps => Vec::new(),
+        }
+    }
+}
+
+fn date() -> DataType {
+    DataType::Reference(define("Date"))
+}
+
+fn bytes() -> DataType {
+    DataType::Reference(define("Uint8Array"))
+}
+
+/// Replace all maps with the [`MAP_BRAND`] opaque type.
+pub(super) fn revive_maps(dt: &mut DataType) {
+    match dt {
+        DataType::Primitive(_) | DataType::Generic(_) => {}
+        DataType::List(list) => revive_maps(&mut list.ty),
+        DataType::Map(map) => {
+            let mut key = map.key_ty().clone();
+            let mut value = map.value_ty().clone();
+            revive_maps(&mut key);
+            revive_maps(&mut value);
+            *dt = DataType::Reference(Reference::opaque(Branded::new(
+                MAP_BRAND,
+                DataType::Tuple(Tuple::new(vec![key, value])),
+            )));
+        }
+        DataType::Struct(strct) => revive_maps_fields(&mut strct.fields),
+        DataType::Enum(enm) => {
+            for (_, variant) in &mut enm.variants {
+                revive_maps_fields(&mut variant.fields);
+            }
+        }
+        DataType::Tuple(tuple) => tuple.elements.iter_mut().for_each(revive_maps),
+        DataType::Nullable(inner) => revive_maps(inner),
+        DataType::Intersection(parts) => parts.iter_mut().for_each(revive_maps),
+        DataType::Reference(Reference::Named(r)) => match &mut r.inner {
+            NamedReferenceType::Reference { generics, .. } => {
+                generics.iter_mut().for_each(|(_, dt)| revive_maps(dt))
+            }
+            NamedReferenceType::Inline { dt, .. } => revive_maps(dt),
+            NamedReferenceType::Recursive(_) => {}
+        },
+        DataType::Reference(Reference::Opaque(_)) => {}
+    }
+}
+
+fn revive_maps_fields(fields: &mut Fields) {
+    match fields {
+        Fields::Unit => {}
+        Fields::Unnamed(unnamed) => unnamed
+            .fields
+            .iter_mut()
+            .filter_map(|field| field.ty.as_mut())
+            .for_each(revive_maps),
+        Fields::Named(named) => named
+            .fields
+            .iter_mut()
+            .filter_map(|(_, field)| field.ty.as_mut())
+            .for_each(revive_maps),
+    }
+}
+
+/// Returns the key and value types of a map that was replaced by [`revive_maps`].
+pub(super) fn revived_map(branded: &Branded) -> Option<(&DataType, &DataType)> {
+    if branded.brand() == MAP_BRAND
+        && let DataType::Tuple(tuple) = branded.ty()
+        && let [key, value] = tuple.elements.as_slice()
+    {
+        return Some((key, value));
+    }
+
+    None
+}
+
+// Generic types are inlined into the descriptors, this limits how deep they can be nested.
+const MAX_GENERIC_DEPTH: usize = 16;
+
+/// Render the `TRANSFORMS` object used by the runtime to convert values that can't be represented in JSON,
+/// returns `None` when none of the procedures need it.
+///
+/// The descriptors describe where the values are located in the arguments and output of a procedure:
+/// `"bigint"`, `"date"`, `"bytes"`, `{ object: { key: D } }`, `{ array: D }`, `{ record: D }`,
+/// `{ map: D | null, key: D | "number" | null }`, `{ tuple: [D | null] }`, `{ union: [D] }`
+/// or `{ ref: "Type" }`, where a `ref` points to a descriptor in `types`.
+pub(super) fn generate_transforms(
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    types: &Types,
+    format: &SpectaFormat,
+) -> Option<String> {
+    let mut transformer = Transformer {
+        types,
+        named: BTreeSet::new(),
+    };
+
+    // Find the named types that contain values that need to be transformed, until nothing changes.
+    loop {
+        let mut changed = false;
+        for ndt in types.into_sorted_iter() {
+            let key = type_key(&ndt.name, &ndt.module_path);
+            if !ndt.generics.is_empty() || transformer.named.contains(&key) {
+                continue;
+            }
+
+            if let Some(ty) = &ndt.ty
+                && transformer.descriptor(ty, &[], 0).is_some()
+            {
+                transformer.named.insert(key);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut procedures = Map::new();
+    for (path, path_functions) in functions {
+        let mut path_procedures = Map::new();
+        for function in path_functions {
+            let name = function.name().split_once("_taurpc_fn__").unwrap().1;
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+
+            let args = function
+                .args()
+                .iter()
+                .map(|(_, dt)| transformer.procedure_descriptor(dt, args_phase(is_event), format))
+                .collect::<Vec<_>>();
+            let output = function.result().and_then(|result| {
+                let result = extract_std_result(result, types)
+                    .map(|(ok, _)| ok)
+                    .unwrap_or(result);
+                transformer.procedure_descriptor(result, Phase::Serialize, format)
+            });
+
+            if output.is_some() || args.iter().any(Option::is_some) {
+                path_procedures.insert(name.to_string(), json!({ "args": args, "output": output }));
+            }
+        }
+
+        if !path_procedures.is_empty() {
+            procedures.insert(path.clone(), Value::Object(path_procedures));
+        }
+    }
+
+    if procedures.is_empty() {
+        return None;
+    }
+
+    let named_types = types
+        .into_sorted_iter()
+        .filter_map(|ndt| {
+            let key = type_key(&ndt.name, &ndt.module_path);
+            let descriptor = transformer.descriptor(ndt.ty.as_ref()?, &[], 0)?;
+            transformer
+                .named
+                .contains(&key)
+                .then_some((key, descriptor))
+        })
+        .collect::<Map<_, _>>();
+
+    let transforms = json!({ "types": named_types, "procedures": procedures });
+    Some(format!(
+        "const TRANSFORMS = {};\n",
+        serde_json::to_string_pretty(&transforms).unwrap()
+    ))
+}
+
+struct Transformer<'a> {
+    types: &'a Types,
+    /// Named types that have a descriptor in `types`.
+    named: BTreeSet<String>,
+}
+
+impl Transformer<'_> {
+    fn procedure_descriptor(
+        &self,
+        dt: &DataType,
+        phase: Phase,
+        format: &SpectaFormat,
+    ) -> Option<Value> {
+        let dt = specta_serde::select_phase_datatype(dt, self.types, phase);
+        let dt = format.remap(dt);
+
+        // Channel messages are not transformed.
+        if let DataType::Reference(Reference::Named(r)) = &dt
+            && self.types.get(r).is_some_and(is_tauri_channel)
+        {
+            return None;
+        }
+
+        self.descriptor(&dt, &[], 0)
+    }
+
+    fn descriptor(
+        &self,
+        dt: &DataType,
+        generics: &[(Generic, Option<Value>)],
+        depth: usize,
+    ) -> Option<Value> {
+        match dt {
+            DataType::Primitive(
+                Primitive::isize
+                | Primitive::usize
+                | Primitive::i64
+                | Primitive::u64
+                | Primitive::i128
+                | Primitive::u128,
+            ) => Some(json!("bigint")),
+            DataType::Primitive(_) => None,
+            DataType::Generic(g) => generics
+                .iter()
+                .find(|(generic, _)| generic == g)
+                .and_then(|(_, descriptor)| descriptor.clone()),
+            DataType::List(list) => self
+                .descriptor(&list.ty, generics, depth)
+                .map(|item| json!({ "array": item })),
+            DataType::Map(map) => self
+                .descriptor(map.value_ty(), generics, depth)
+                .map(|value| json!({ "record": value })),
+            // The runtime skips `null`, so the inner descriptor is used as is.
+            DataType::Nullable(inner) => self.descriptor(inner, generics, depth),
+            DataType::Struct(strct) => self.fields(&strct.fields, generics, depth),
+            DataType::Enum(enm) => union(
+                enm.variants
+                    .iter()
+                    .filter(|(_, variant)| !variant.skip)
+                    .filter_map(|(_, variant)| self.fields(&variant.fields, generics, depth))
+                    .collect(),
+            ),
+            DataType::Tuple(tuple) => self.tuple(
+                tuple
+                    .elements
+                    .iter()
+                    .map(|dt| self.descriptor(dt, generics, depth))
+                    .collect(),
+            ),
+            DataType::Intersection(parts) => union(
+                parts
+                    .iter()
+                    .filter_map(|dt| self.descriptor(dt, generics, depth))
+                    .collect(),
+            ),
+            DataType::Reference(Reference::Named(r)) => match &r.inner {
+                NamedReferenceType::Reference { generics: args, .. } => {
+                    let ndt = self.types.get(r)?;
+
+                    if ndt.generics.is_empty() {
+                        let key = type_key(&ndt.name, &ndt.module_path);
+                        self.named.contains(&key).then(|| json!({ "ref": key }))
+                    } else if depth < MAX_GENERIC_DEPTH {
+                        let generics = ndt
+                            .generics
+                            .iter()
+                            .map(|definition| {
+                                let descriptor = args
+                                    .iter()
+                                    .find(|(generic, _)| *generic == definition.reference())
+                                    .and_then(|(_, dt)| self.descriptor(dt, generics, depth));
+                                (definition.reference(), descriptor)
+                            })
+                            .collect::<Vec<_>>();
+                        self.descriptor(ndt.ty.as_ref()?, &generics, depth + 1)
+                    } else {
+                        None
+                    }
+                }
+                NamedReferenceType::Inline { dt, .. } => self.descriptor(dt, generics, depth),
+                NamedReferenceType::Recursive(_) => None,
+            },
+            DataType::Reference(Reference::Opaque(opaque)) => {
+                if let Some((key, value)) = opaque.downcast_ref().and_then(revived_map) {
+                    // Object keys are always strings, numeric keys are converted back.
+                    let key = self
+                        .descriptor(key, generics, depth)
+                        .or_else(|| is_number(key).then(|| json!("number")));
+                    return Some(json!({
+                        "map": self.descriptor(value, generics, depth),
+                        "key": key,
+                    }));
+                }
+
+                let is = |dt: DataType| matches!(dt, DataType::Reference(Reference::Opaque(other)) if other == *opaque);
+                if is(<specta_typescript::BigInt as Type>::definition(
+                    &mut Types::default(),
+                )) {
+                    Some(json!("bigint"))
+                } else if is(date()) {
+                    Some(json!("date"))
+                } else if is(bytes()) {
+                    Some(json!("bytes"))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn fields(
+        &self,
+        fields: &Fields,
+        generics: &[(Generic, Option<Value>)],
+        depth: usize,
+    ) -> Option<Value> {
+        match fields {
+            Fields::Unit => None,
+            Fields::Unnamed(unnamed) => {
+                let fields = unnamed
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.ty.as_ref())
+                    .map(|dt| self.descriptor(dt, generics, depth))
+                    .collect::<Vec<_>>();
+
+                match (fields.as_slice(), unnamed.fields.len()) {
+                    ([field], 1) => field.clone(),
+                    _ => self.tuple(fields),
+                }
+            }
+            Fields::Named(named) => {
+                let fields = named
+                    .fields
+                    .iter()
+                    .filter_map(|(name, field)| {
+                        let descriptor = self.descriptor(field.ty.as_ref()?, generics, depth)?;
+                        Some((name.to_string(), descriptor))
+                    })
+                    .collect::<Map<_, _>>();
+
+                (!fields.is_empty()).then(|| json!({ "object": fields }))
+            }
+        }
+    }
+
+    fn tuple(&self, elements: Vec<Option<Value>>) -> Option<Value> {
+        elements
+            .iter()
+            .any(Option::is_some)
+            .then(|| json!({ "tuple": elements }))
+    }
+}
+
+fn is_number(dt: &DataType) -> bool {
+    match dt {
+        DataType::Primitive(p) => !matches!(p, Primitive::bool | Primitive::char | Primitive::str),
+        dt => *dt == <specta_typescript::Number as Type>::definition(&mut Types::default()),
+    }
+}
+
+fn union(mut descriptors: Vec<Value>) -> Option<Value> {
+    descriptors.dedup();
+    match descriptors.len() {
+        0 => None,
+        1 => descriptors.pop(),
+        _ => Some(json!({ "union": descriptors })),
+    }
+}
+
+fn type_key(name: &str, module_path: &str) -> String {
+    format!("{module_path}::{name}")
+}
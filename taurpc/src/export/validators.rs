@@ -0,0 +1,580 @@
+use specta::{
+    Type, Types,
+    datatype::{
+        DataType, Fields, Function, NamedDataType, NamedReferenceType, OpaqueReference, Primitive,
+        Reference,
+    },
+};
+use specta_serde::Phase;
+use specta_typescript::{Error, FrameworkExporter, define, primitives};
+use std::collections::BTreeMap;
+
+use super::{
+    Output, SpectaFormat, args_phase, extract_std_result, is_optional_arg, is_tauri_channel,
+    render_reference_dt, router_type_ref, takes_object_args, transforms::revived_map,
+};
+
+/// The runtime validation library used for the schemas generated by [`Exporter::validators`](super::Exporter::validators).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validator {
+    /// Generate [zod](https://zod.dev) schemas, requires `zod` to be installed on the frontend.
+    Zod,
+    /// Generate [valibot](https://valibot.dev) schemas, requires `valibot` to be installed on the frontend.
+    Valibot,
+}
+
+impl Validator {
+    fn import(&self) -> &'static str {
+        match self {
+            Self::Zod => "import { z } from 'zod'",
+            Self::Valibot => "import * as v from 'valibot'",
+        }
+    }
+
+    fn schema_type(&self) -> &'static str {
+        match self {
+            Self::Zod => "z.ZodType",
+            Self::Valibot => "v.GenericSchema",
+        }
+    }
+
+    fn call(&self, function: &str, args: &str) -> String {
+        match self {
+            Self::Zod => format!("z.{function}({args})"),
+            Self::Valibot => format!("v.{function}({args})"),
+        }
+    }
+
+    fn primitive(&self, p: &Primitive) -> String {
+        use Primitive::*;
+
+        match (self, p) {
+            (Self::Zod, i8 | i16 | i32 | u8 | u16 | u32) => "z.number().int()".into(),
+            (Self::Valibot, i8 | i16 | i32 | u8 | u16 | u32) => {
+                "v.pipe(v.number(), v.integer())".into()
+            }
+            // `NaN` and `Infinity` are serialized as `null` by Serde JSON.
+            (_, f16 | f32 | f64 | f128) => self.nullable(&self.call("number", "")),
+            (_, usize | isize | i64 | u64 | i128 | u128) => self.call("number", ""),
+            (_, bool) => self.call("boolean", ""),
+            (_, str | char) => self.call("string", ""),
+        }
+    }
+
+    fn nullable(&self, schema: &str) -> String {
+        match self {
+            Self::Zod => format!("{schema}.nullable()"),
+            Self::Valibot => format!("v.nullable({schema})"),
+        }
+    }
+
+    fn optional(&self, schema: &str) -> String {
+        match self {
+            Self::Zod => format!("{schema}.optional()"),
+            Self::Valibot => format!("v.optional({schema})"),
+        }
+    }
+
+    /// The type arguments are omitted from JavaScript output.
+    fn custom(&self, ts_type: Option<&str>) -> String {
+        let type_args = type_args(ts_type);
+        match self {
+            Self::Zod => format!("z.custom{type_args}()"),
+            Self::Valibot => format!("v.custom{type_args}(() => true)"),
+        }
+    }
+
+    fn instance(&self, class: &str) -> String {
+        match self {
+            Self::Zod => format!("z.instanceof({class})"),
+            Self::Valibot => format!("v.instance({class})"),
+        }
+    }
+
+    fn function(&self, ts_type: Option<&str>) -> String {
+        let type_args = type_args(ts_type);
+        match self {
+            Self::Zod => format!("z.custom{type_args}((value) => typeof value === 'function')"),
+            Self::Valibot => {
+                format!("v.custom{type_args}((value) => typeof value === 'function')")
+            }
+        }
+    }
+
+    /// The type of the values accepted by the generic schema `schema`.
+    fn infer(&self, schema: &str) -> String {
+        match self {
+            Self::Zod => format!("z.infer<{schema}>"),
+            Self::Valibot => format!("v.InferOutput<{schema}>"),
+        }
+    }
+
+    fn lazy(&self, schema: &str) -> String {
+        self.call("lazy", &format!("() => {schema}"))
+    }
+
+    fn list(&self, schemas: Vec<String>) -> String {
+        format!("[{}]", schemas.join(", "))
+    }
+
+    fn union(&self, mut schemas: Vec<String>) -> String {
+        match schemas.len() {
+            0 => self.call("never", ""),
+            1 => schemas.remove(0),
+            _ => self.call("union", &self.list(schemas)),
+        }
+    }
+
+    fn intersection(&self, schemas: Vec<String>) -> String {
+        match self {
+            Self::Zod => schemas
+                .into_iter()
+                .reduce(|acc, schema| format!("z.intersection({acc}, {schema})"))
+                .unwrap_or_else(|| "z.unknown()".into()),
+            Self::Valibot => self.call("intersect", &self.list(schemas)),
+        }
+    }
+}
+
+/// Render the schemas for all exported types and the `PROCEDURE_SCHEMAS` object.
+pub(super) fn generate_validators(
+    validator: Validator,
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+    object_args: &BTreeMap<String, Vec<String>>,
+    exporter: &FrameworkExporter,
+    format: &SpectaFormat,
+    output: Output,
+) -> Result<String, Error> {
+    let renderer = SchemaRenderer {
+        validator,
+        exporter,
+        output,
+    };
+
+    let mut out = format!("\n{}\n\n", validator.import());
+
+    for ndt in exporter.types.into_sorted_iter() {
+        let Some(ty) = &ndt.ty else {
+            continue;
+        };
+
+        out.push_str(&renderer.named_schema(ndt, ty)?);
+        out.push('\n');
+    }
+
+    if output == Output::Declarations {
+        out.push_str(&procedure_schemas_declaration(validator, functions, events));
+        return Ok(out);
+    }
+
+    out.push_str("export const PROCEDURE_SCHEMAS = {\n");
+    for (path, path_functions) in functions {
+        let mut path_functions: Vec<_> = path_functions
+            .iter()
+            .map(|f| (f.name().split_once("_taurpc_fn__").unwrap().1, f))
+            .collect();
+        path_functions.sort_by(|a, b| a.0.cmp(b.0));
+
+        out.push_str(&format!("\t{}: {{\n", object_key(path)));
+        for (name, function) in path_functions {
+            let object_args = takes_object_args(object_args, path, function);
+            let is_event = events
+                .get(path)
+                .is_some_and(|events| events.iter().any(|event| event == name));
+            let (input, output) =
+                renderer.procedure_schemas(function, object_args, is_event, format)?;
+            out.push_str(&format!(
+                "\t\t{}: {{\n\t\t\tinput: {input},\n\t\t\toutput: {output},\n\t\t}},\n",
+                object_key(name)
+            ));
+        }
+        out.push_str("\t},\n");
+    }
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+struct SchemaRenderer<'a, 'b> {
+    validator: Validator,
+    exporter: &'a FrameworkExporter<'b>,
+    output: Output,
+}
+
+impl SchemaRenderer<'_, '_> {
+    fn named_schema(&self, ndt: &NamedDataType, ty: &DataType) -> Result<String, Error> {
+        let name = schema_name(ndt);
+        let schema_type = self.validator.schema_type();
+
+        if ndt.generics.is_empty() {
+            // The annotation keeps the schema in sync with the generated TS type,
+            // it also allows (mutually) recursive schemas.
+            let ts_type =
+                primitives::reference(self.exporter, self.exporter.types, &ndt.reference(vec![]))?;
+            Ok(match self.output {
+                Output::Typescript => format!(
+                    "export const {name}: {schema_type}<{ts_type}> = {}\n",
+                    self.schema(ty, 0)?
+                ),
+                Output::Javascript => format!("export const {name} = {}\n", self.schema(ty, 0)?),
+                Output::Declarations => {
+                    format!("export declare const {name}: {schema_type}<{ts_type}>\n")
+                }
+            })
+        } else {
+            let type_params = ndt
+                .generics
+                .iter()
+                .map(|g| format!("{} extends {schema_type}", g.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let params = ndt
+                .generics
+                .iter()
+                .map(|g| format!("{}: {}", g.name, g.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            Ok(match self.output {
+                Output::Typescript => format!(
+                    "export const {name} = <{type_params}>({params}) => {}\n",
+                    self.schema(ty, 0)?
+                ),
+                Output::Javascript => {
+                    let params = ndt
+                        .generics
+                        .iter()
+                        .map(|g| g.name.as_ref())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(
+                        "export const {name} = ({params}) => {}\n",
+                        self.schema(ty, 0)?
+                    )
+                }
+                Output::Declarations => {
+                    let generics = ndt
+                        .generics
+                        .iter()
+                        .map(|g| {
+                            let ts_type = define(self.validator.infer(&g.name));
+                            (g.reference(), DataType::Reference(ts_type))
+                        })
+                        .collect();
+                    let ts_type = primitives::reference(
+                        self.exporter,
+                        self.exporter.types,
+                        &ndt.reference(generics),
+                    )?;
+                    format!(
+                        "export declare const {name}: <{type_params}>({params}) => {schema_type}<{ts_type}>\n"
+                    )
+                }
+            })
+        }
+    }
+
+    fn procedure_schemas(
+        &self,
+        function: &Function,
+        object_args: bool,
+        is_event: bool,
+        format: &SpectaFormat,
+    ) -> Result<(String, String), Error> {
+        let input = if object_args && !function.args().is_empty() {
+            // A single argument, with a field for each argument of the procedure.
+            let mut fields = String::new();
+            for (idx, (name, dt)) in function.args().iter().enumerate() {
+                let mut schema = self.procedure_schema(dt, args_phase(is_event), format, 4)?;
+                if is_optional_arg(function, idx, true) {
+                    schema = self.validator.optional(&schema);
+                }
+                fields.push_str(&format!("\t\t\t\t{}: {schema},\n", object_key(name)));
+            }
+            let object = self
+                .validator
+                .call("object", &format!("{{\n{fields}\t\t\t}}"));
+            self.validator
+                .call("tuple", &self.validator.list(vec![object]))
+        } else {
+            let input = function
+                .args()
+                .iter()
+                .enumerate()
+                .map(|(idx, (_, dt))| {
+                    let schema = self.procedure_schema(dt, args_phase(is_event), format, 3)?;
+                    Ok(match is_optional_arg(function, idx, false) {
+                        true => self.validator.optional(&schema),
+                        false => schema,
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            self.validator.call("tuple", &self.validator.list(input))
+        };
+
+        let output = match function.result() {
+            Some(result) => {
+                let result = extract_std_result(result, self.exporter.types)
+                    .map(|(ok, _)| ok)
+                    .unwrap_or(result);
+                self.procedure_schema(result, Phase::Serialize, format, 3)?
+            }
+            None => self.validator.call("void", ""),
+        };
+
+        Ok((input, output))
+    }
+
+    fn procedure_schema(
+        &self,
+        dt: &DataType,
+        phase: Phase,
+        format: &SpectaFormat,
+        depth: usize,
+    ) -> Result<String, Error> {
+        let dt = specta_serde::select_phase_datatype(dt, self.exporter.types, phase);
+        let dt = format.remap(dt);
+
+        if let DataType::Reference(Reference::Named(r)) = &dt
+            && let Some(ndt) = self.exporter.types.get(r)
+            && is_tauri_channel(ndt)
+        {
+            let ts_type = render_reference_dt(&dt, self.exporter)?;
+            return Ok(self.validator.function(self.ts_type(&ts_type)));
+        }
+
+        self.schema(&dt, depth)
+    }
+
+    /// TypeScript types can't be used in JavaScript output.
+    fn ts_type<'t>(&self, ts_type: &'t str) -> Option<&'t str> {
+        (self.output != Output::Javascript).then_some(ts_type)
+    }
+
+    fn schema(&self, dt: &DataType, depth: usize) -> Result<String, Error> {
+        let v = self.validator;
+
+        Ok(match dt {
+            DataType::Primitive(p) => v.primitive(p),
+            DataType::Generic(g) => g.name().to_string(),
+            DataType::List(list) => {
+                let item = self.schema(&list.ty, depth)?;
+                match list.length {
+                    Some(length) => v.call("tuple", &v.list(vec![item; length])),
+                    None => v.call("array", &item),
+                }
+            }
+            // JSON object keys are always strings, so we don't validate the key type.
+            DataType::Map(map) => v.call(
+                "record",
+                &format!(
+                    "{}, {}",
+                    v.call("string", ""),
+                    self.schema(map.value_ty(), depth)?
+                ),
+            ),
+            DataType::Nullable(inner) => v.nullable(&self.schema(inner, depth)?),
+            DataType::Struct(strct) => self.fields(&strct.fields, depth)?,
+            DataType::Enum(enm) => {
+                let variants = enm
+                    .variants
+                    .iter()
+                    .filter(|(_, variant)| !variant.skip)
+                    .map(|(name, variant)| match &variant.fields {
+                        Fields::Unit => {
+                            Ok(v.call("literal", &serde_json::to_string(name).unwrap()))
+                        }
+                        fields => self.fields(fields, depth),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                v.union(variants)
+            }
+            DataType::Tuple(tuple) => match tuple.elements.as_slice() {
+                [] => v.call("null", ""),
+                elements => {
+                    let elements = elements
+                        .iter()
+                        .map(|dt| self.schema(dt, depth))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    v.call("tuple", &v.list(elements))
+                }
+            },
+            DataType::Intersection(parts) => v.intersection(
+                parts
+                    .iter()
+                    .map(|dt| self.schema(dt, depth))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            DataType::Reference(Reference::Named(r)) => match &r.inner {
+                NamedReferenceType::Reference { generics, .. } => {
+                    let Some(ndt) = self.exporter.types.get(r) else {
+                        return Ok(v.call("unknown", ""));
+                    };
+
+                    if ndt.generics.is_empty() {
+                        v.lazy(&schema_name(ndt))
+                    } else {
+                        let args = ndt
+                            .generics
+                            .iter()
+                            .map(|definition| {
+                                generics
+                                    .iter()
+                                    .find(|(generic, _)| *generic == definition.reference())
+                                    .map(|(_, dt)| self.schema(dt, depth))
+                                    .unwrap_or_else(|| Ok(v.call("unknown", "")))
+                            })
+                            .collect::<Result<Vec<_>, _>>()?
+                            .join(", ");
+                        v.lazy(&format!("{}({args})", schema_name(ndt)))
+                    }
+                }
+                NamedReferenceType::Inline { dt, .. } => self.schema(dt, depth)?,
+                NamedReferenceType::Recursive(_) => v.call("unknown", ""),
+            },
+            DataType::Reference(r @ Reference::Opaque(opaque)) => self.opaque(r, opaque, depth)?,
+        })
+    }
+
+    fn fields(&self, fields: &Fields, depth: usize) -> Result<String, Error> {
+        let v = self.validator;
+
+        Ok(match fields {
+            Fields::Unit => v.call("null", ""),
+            Fields::Unnamed(unnamed) => {
+                let fields = unnamed
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.ty.as_ref())
+                    .map(|dt| self.schema(dt, depth))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match (fields.as_slice(), unnamed.fields.len()) {
+                    ([field], 1) => field.clone(),
+                    _ => v.call("tuple", &v.list(fields)),
+                }
+            }
+            Fields::Named(named) => {
+                let indent = "\t".repeat(depth + 1);
+                let mut fields = String::new();
+                for (name, field) in &named.fields {
+                    let Some(dt) = &field.ty else {
+                        continue;
+                    };
+
+                    let mut schema = self.schema(dt, depth + 1)?;
+                    if field.optional {
+                        schema = v.optional(&schema);
+                    }
+                    fields.push_str(&format!("{indent}{}: {schema},\n", object_key(name)));
+                }
+
+                if fields.is_empty() {
+                    v.call("object", "{}")
+                } else {
+                    let indent = "\t".repeat(depth);
+                    v.call("object", &format!("{{\n{fields}{indent}}}"))
+                }
+            }
+        })
+    }
+
+    fn opaque(
+        &self,
+        r: &Reference,
+        opaque: &OpaqueReference,
+        depth: usize,
+    ) -> Result<String, Error> {
+        let v = self.validator;
+        if let Some((key, value)) = opaque.downcast_ref().and_then(revived_map) {
+            let entries = format!(
+                "{}, {}",
+                self.schema(key, depth)?,
+                self.schema(value, depth)?
+            );
+            return Ok(v.call("map", &entries));
+        }
+
+        let is = |dt: DataType| matches!(dt, DataType::Reference(Reference::Opaque(other)) if other == *opaque);
+        let mut types = Types::default();
+
+        Ok(
+            if is(<specta_typescript::Number as Type>::definition(&mut types)) {
+                v.call("number", "")
+            } else if is(<specta_typescript::BigInt as Type>::definition(&mut types)) {
+                v.call("bigint", "")
+            } else if is(<specta_typescript::Any as Type>::definition(&mut types)) {
+                v.call("any", "")
+            } else if is(<specta_typescript::Unknown as Type>::definition(&mut types)) {
+                v.call("unknown", "")
+            } else if is(<specta_typescript::Never as Type>::definition(&mut types)) {
+                v.call("never", "")
+            } else {
+                match self.exporter.reference(r)?.as_ref() {
+                    // Large integers are defined as `string` by `BigIntExport::String`.
+                    "string" => v.call("string", ""),
+                    // Revived by the generated client, see `Revive`.
+                    "Date" => v.call("date", ""),
+                    "Uint8Array" => v.instance("Uint8Array"),
+                    // Used by `OptionalFields::Undefined`.
+                    "undefined" => v.call("undefined", ""),
+                    ts_type => v.custom(self.ts_type(ts_type)),
+                }
+            },
+        )
+    }
+}
+
+/// Declare the type of `PROCEDURE_SCHEMAS`, based on the procedures of the `Router` and `RouterEvents` types.
+fn procedure_schemas_declaration(
+    validator: Validator,
+    functions: &BTreeMap<String, Vec<Function>>,
+    events: &BTreeMap<String, Vec<String>>,
+) -> String {
+    let schema_type = validator.schema_type();
+    let mut out = String::from("export declare const PROCEDURE_SCHEMAS: {\n");
+    for (path, path_functions) in functions {
+        let mut names: Vec<_> = path_functions
+            .iter()
+            .map(|f| f.name().split_once("_taurpc_fn__").unwrap().1)
+            .collect();
+        names.sort();
+
+        out.push_str(&format!("\t{}: {{\n", object_key(path)));
+        for name in names {
+            let procedure = router_type_ref(events, path, name);
+            out.push_str(&format!(
+                "\t\t{}: {{\n\t\t\tinput: {schema_type}<Parameters<{procedure}>>,\n\t\t\toutput: {schema_type}<Awaited<ReturnType<{procedure}>>>,\n\t\t}},\n",
+                object_key(name)
+            ));
+        }
+        out.push_str("\t},\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn type_args(ts_type: Option<&str>) -> String {
+    ts_type
+        .map(|ts_type| format!("<{ts_type}>"))
+        .unwrap_or_default()
+}
+
+fn schema_name(ndt: &NamedDataType) -> String {
+    format!("{}Schema", ndt.name)
+}
+
+fn object_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        key.to_string()
+    } else {
+        serde_json::to_string(key).unwrap()
+    }
+}
@@ -11,17 +11,39 @@ use specta::datatype::Function;
 pub use specta_typescript::Typescript;
 
 use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::{fmt::Debug, sync::Arc};
 use tokio::sync::broadcast::Sender;
 
+use bigint::BigIntSerialize;
+
+use procedure::DynProcedure;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
+use specta::Type;
 use tauri::ipc::{Invoke, InvokeError};
-use tauri::{AppHandle, Emitter, EventTarget, Runtime};
+use tauri::{AppHandle, Emitter, EventTarget, Manager, Runtime};
 
-pub use taurpc_macros::{ipc_type, procedures, resolvers};
+pub use taurpc_macros::{collect_procedures, constant, ipc_type, procedure, procedures, resolvers};
 
+mod bigint;
 mod export;
-pub use export::{ExportError, Exportable, Exporter};
+mod procedure;
+pub use bigint::{BigIntExport, BigIntPolicy};
+pub use export::{
+    ClientRuntime, Constant, ExportError, Exportable, Exporter, Framework, OptionalFields,
+    PackageFormat, RenameRule, Revive, RuleSet, TypeCollisions, Validator,
+};
+
+/// Used by the code generated by the macros, not public API.
+#[doc(hidden)]
+pub mod private {
+    pub use crate::bigint::{
+        CommandArgKind, DeserializeArgKind, RawResponseKind, RawResultResponseKind, ResponseRef,
+        ResultResponseKind, ValueResponseKind, invoke_policy,
+    };
+    pub use crate::procedure::{Procedure, args_map};
+}
 
 /// A trait, which is automatically implemented by `#[taurpc::procedures]`, that is used for handling incoming requests
 /// and the type generation.
@@ -31,7 +53,8 @@ pub trait TauRpcHandler<R: Runtime>: Sized {
     /// This handler's prefix in the TypeScript router.
     const PATH_PREFIX: &'static str;
 
-    /// Handle a single incoming request
+    /// Handle a single incoming request, large integers are serialized according to the [`BigIntPolicy`]
+    /// that is managed by the app.
     fn handle_incoming_request(self, invoke: Invoke<R>);
 
     /// Spawn a new `tokio` thread that listens for and handles incoming request through a `tokio::broadcast::channel`.
@@ -42,10 +65,28 @@ pub trait TauRpcHandler<R: Runtime>: Sized {
     /// This is used on the frontend to ensure the arguments are send with their correct idents to the backend.
     fn args_map() -> String;
 
+    /// Returns the names of the procedures that are marked as `#[taurpc(event)]`.
+    fn events() -> Vec<String>;
+
+    /// Returns the names of the procedures that take their arguments as a single object, `#[taurpc(args = "object")]`.
+    fn object_args() -> Vec<String>;
+
+    /// Returns the doc comments of the trait and the arguments of the procedures.
+    fn docs() -> PathDocs;
+
     /// Returns all of the functions for exporting, all referenced types will be added to `types`.
     fn collect_fn_types(types: &mut Types) -> Vec<Function>;
 }
 
+/// The doc comments of a procedures trait that aren't part of the exported functions.
+#[derive(Debug, Clone, Default)]
+pub struct PathDocs {
+    /// The doc comments of the trait, added to its path in the exported router.
+    pub docs: String,
+    /// The doc comments of the arguments, keyed by procedure and argument name.
+    pub args: BTreeMap<String, BTreeMap<String, String>>,
+}
+
 /// Creates a handler that allows your IPCs to be called from the frontend with the coresponding
 /// types. Accepts a struct in which your `taurpc::procedures` trait is implemented.
 /// If you have nested routes, look at [taurpc::Router](https://docs.rs/taurpc/latest/taurpc/struct.Router.html).
@@ -98,6 +139,7 @@ struct Event<S> {
 /// A structure used for triggering [tauri events](https://v2.tauri.app/develop/calling-rust/#accessing-the-webviewwindow-in-commands) on the frontend.
 /// By default the events are send to all windows with `emit_all`, if you want to send to a specific window by label,
 /// use `new_scoped` or `new_scoped_from_trigger`.
+/// The events are serialized according to the [`BigIntPolicy`] that is managed by the app, see [`Router::bigint_policy`].
 #[derive(Debug)]
 pub struct EventTrigger<RT: Runtime> {
     app_handle: AppHandle<RT>,
@@ -117,11 +159,7 @@ impl<RT: Runtime> Clone for EventTrigger<RT> {
 
 impl<RT: Runtime> EventTrigger<RT> {
     pub fn new(app_handle: AppHandle<RT>, path_prefix: String) -> Self {
-        Self {
-            app_handle,
-            path_prefix,
-            target: EventTarget::Any,
-        }
+        Self::new_scoped(app_handle, path_prefix, EventTarget::Any)
     }
 
     pub fn new_scoped<I: Into<EventTarget>>(
@@ -150,7 +188,10 @@ impl<RT: Runtime> EventTrigger<RT> {
         } else {
             format!("{}.{}", self.path_prefix, proc_name)
         };
-        let event = Event { event_name, event };
+        let event = Event {
+            event_name,
+            event: BigIntSerialize(event, BigIntPolicy::managed(&self.app_handle)),
+        };
         let _ = self
             .app_handle
             .emit_to(self.target.clone(), "TauRpc_event", event);
@@ -200,6 +241,16 @@ pub struct Router<R: Runtime> {
     pub(crate) handlers: HashMap<String, Sender<Arc<Invoke<R>>>>,
     pub(crate) args_map_json: BTreeMap<String, String>,
     pub(crate) fns_map: BTreeMap<String, Vec<Function>>,
+    pub(crate) events: BTreeMap<String, Vec<String>>,
+    pub(crate) object_args: BTreeMap<String, Vec<String>>,
+    pub(crate) docs: BTreeMap<String, PathDocs>,
+    /// The procedures registered with `Router::procedure`, keyed by their full path.
+    pub(crate) procedures: HashMap<String, DynProcedure<R>>,
+    /// The exported functions of the procedures registered with `Router::procedure`, keyed by path.
+    pub(crate) procedure_fns: BTreeMap<String, Vec<Function>>,
+    /// The procedures registered with `Router::procedure` that can't be exported, these fail the export.
+    pub(crate) procedure_errors: Vec<String>,
+    pub(crate) bigint: BigIntPolicy,
 }
 
 impl<R: Runtime> Router<R> {
@@ -209,9 +260,50 @@ impl<R: Runtime> Router<R> {
             handlers: HashMap::new(),
             fns_map: BTreeMap::new(),
             args_map_json: BTreeMap::new(),
+            events: BTreeMap::new(),
+            object_args: BTreeMap::new(),
+            docs: BTreeMap::new(),
+            procedures: HashMap::new(),
+            procedure_fns: BTreeMap::new(),
+            procedure_errors: Vec::new(),
+            bigint: BigIntPolicy::default(),
         }
     }
 
+    /// Choose how 64 and 128-bit integers are sent to the frontend, see [`BigIntExport`].
+    /// This applies to all procedures of the router, and is used by the [`Exporter`] for the types.
+    /// Events use the same policy, see [`Router::bigint_policy`].
+    ///
+    /// ```rust,ignore
+    /// let router = taurpc::Router::new()
+    ///     .bigint(taurpc::BigIntExport::BigInt)
+    ///     .merge(ApiImpl.into_handler());
+    /// ```
+    pub fn bigint(mut self, mode: BigIntExport) -> Self {
+        self.bigint.mode = mode;
+        self
+    }
+
+    /// Return an error instead of losing precision, when a 64 or 128-bit integer that is outside of
+    /// JavaScript's safe integer range is sent to the frontend as a `number`.
+    pub fn bigint_guard(mut self, enabled: bool) -> Self {
+        self.bigint.guard = enabled;
+        self
+    }
+
+    /// Returns the [`BigIntPolicy`] of the router. The router manages the policy in the state of the app when the
+    /// first procedure is called, manage it yourself to send events with the same policy before that.
+    /// A different policy that is managed by the app is rejected by the router.
+    ///
+    /// ```rust,ignore
+    /// tauri::Builder::default()
+    ///     .manage(router.bigint_policy())
+    ///     .invoke_handler(router.into_handler())
+    /// ```
+    pub fn bigint_policy(&self) -> BigIntPolicy {
+        self.bigint
+    }
+
     /// Add routes to the router, accepts a struct for which a `#[taurpc::procedures]` trait is implemented
     ///
     /// ```rust,ignore
@@ -219,15 +311,83 @@ impl<R: Runtime> Router<R> {
     ///     .merge(ApiImpl.into_handler())
     ///     .merge(EventsImpl.into_handler());
     /// ```
-    pub fn merge<H: TauRpcHandler<R>>(mut self, handler: H) -> Self {
+    pub fn merge<H: TauRpcHandler<R>>(self, handler: H) -> Self {
+        self.merge_at(H::PATH_PREFIX, handler)
+    }
+
+    /// Add routes to the router at `path_prefix`, instead of the `path` of the `#[taurpc::procedures]` trait.
+    /// This allows mounting the same trait more than once, the types are exported for each mount.
+    /// Use `new_at` on the event trigger of the trait to send events to a mount.
+    ///
+    /// ```rust,ignore
+    /// let router = taurpc::Router::new()
+    ///     .merge_at("workspaces.first", WorkspaceImpl::new(first).into_handler())
+    ///     .merge_at("workspaces.second", WorkspaceImpl::new(second).into_handler());
+    ///
+    /// let trigger = WorkspaceEventTrigger::new_at(app_handle, "workspaces.first");
+    /// ```
+    pub fn merge_at<H: TauRpcHandler<R>>(
+        mut self,
+        path_prefix: impl Into<String>,
+        handler: H,
+    ) -> Self {
+        let path_prefix = path_prefix.into();
         self.args_map_json
-            .insert(H::PATH_PREFIX.to_string(), H::args_map());
-        self.fns_map.insert(
-            H::PATH_PREFIX.to_string(),
-            H::collect_fn_types(&mut self.types),
+            .insert(path_prefix.clone(), H::args_map());
+        self.fns_map
+            .insert(path_prefix.clone(), H::collect_fn_types(&mut self.types));
+        self.events.insert(path_prefix.clone(), H::events());
+        self.object_args
+            .insert(path_prefix.clone(), H::object_args());
+        self.docs.insert(path_prefix.clone(), H::docs());
+        self.handlers.insert(path_prefix, handler.spawn());
+        self
+    }
+
+    /// Register a single procedure at `path`, without a `#[taurpc::procedures]` trait. This allows registering
+    /// procedures from data, e.g. for plugins or feature-flagged modules. The procedure takes a single argument,
+    /// `args`, and is exported like the procedures of a trait.
+    ///
+    /// A path without a name, or a path that is registered more than once or is also used by a procedure of a
+    /// merged handler, fails the export. At runtime the last registered procedure takes precedence.
+    ///
+    /// ```rust,ignore
+    /// let router = taurpc::Router::new()
+    ///     .procedure("plugins.greet", |args: GreetArgs| async move {
+    ///         format!("Hello, {}!", args.name)
+    ///     });
+    /// ```
+    pub fn procedure<In, Out, F, Fut>(mut self, path: impl Into<String>, handler: F) -> Self
+    where
+        In: DeserializeOwned + Type + Send + 'static,
+        Out: Serialize + Type + 'static,
+        F: Fn(In) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Out> + Send + 'static,
+    {
+        let path = path.into();
+        let (path_prefix, name) = path.rsplit_once('.').unwrap_or(("", &path));
+        if name.is_empty() {
+            self.procedure_errors.push(format!(
+                "`{path}` doesn't end with the name of the procedure"
+            ));
+            return self;
+        }
+        if self.procedures.contains_key(&path) {
+            self.procedure_errors
+                .push(format!("`{path}` is registered more than once"));
+        }
+
+        self.procedures.insert(
+            path.clone(),
+            procedure::dyn_procedure(name.to_string(), handler),
         );
-        self.handlers
-            .insert(H::PATH_PREFIX.to_string(), handler.spawn());
+        let function = procedure::dyn_function::<In, Out>(path_prefix, name, &mut self.types);
+        let functions = self
+            .procedure_fns
+            .entry(path_prefix.to_string())
+            .or_default();
+        functions.retain(|f| f.name() != function.name());
+        functions.push(function);
         self
     }
 
@@ -250,9 +410,23 @@ impl<R: Runtime> Router<R> {
             return false;
         }
 
+        // The handlers and event triggers read the policy from the state of the app.
+        let webview = invoke.message.webview();
+        if !webview.manage(self.bigint) && *webview.state::<BigIntPolicy>() != self.bigint {
+            invoke.resolver.invoke_error(InvokeError::from(
+                "the `BigIntPolicy` managed by the app is not the policy of the router",
+            ));
+            return true;
+        }
+
         // Remove `TauRPC__`
-        let prefix = cmd[8..].to_string();
-        let mut prefix = prefix.split('.').collect::<Vec<_>>();
+        let path = cmd[8..].to_string();
+        if let Some(procedure) = self.procedures.get(&path) {
+            procedure(invoke, self.bigint);
+            return true;
+        }
+
+        let mut prefix = path.split('.').collect::<Vec<_>>();
         // Remove the actual name of the command
         prefix.pop().unwrap();
 
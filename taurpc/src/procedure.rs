@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use heck::ToUpperCamelCase;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use specta::datatype::{DataType, Function};
+use specta::{Type, Types};
+use tauri::Runtime;
+use tauri::ipc::{Invoke, InvokeBody, InvokeError};
+
+use crate::bigint::{self, BigIntPolicy, ValueResponseTag};
+
+/// Implemented by `#[taurpc::procedure]` for a hidden struct next to the function,
+/// the functions are collected into a handler by `taurpc::collect_procedures!`.
+pub trait Procedure {
+    /// The name of the procedure in the router, the `alias` or the name of the function.
+    const NAME: &'static str;
+
+    /// Whether the arguments are passed as a single object, `#[taurpc::procedure(args = "object")]`.
+    const OBJECT_ARGS: bool;
+
+    /// Handle a single incoming request, large integers are serialized according to `policy`.
+    fn handle<R: Runtime>(invoke: Invoke<R>, policy: BigIntPolicy);
+
+    /// Returns the keys of the arguments sent by the frontend, in order.
+    fn args() -> Vec<String>;
+
+    /// Returns the doc comments of the arguments, keyed by argument name.
+    fn arg_docs() -> BTreeMap<String, String>;
+
+    /// Returns the function for exporting, all referenced types will be added to `types`.
+    fn function(types: &mut Types) -> Function;
+}
+
+/// Serialize the arguments of the procedures, like `TauRpcHandler::args_map`.
+pub fn args_map(args: BTreeMap<&str, Vec<String>>) -> String {
+    serde_json::to_string(&args).unwrap()
+}
+
+/// A procedure registered with `Router::procedure`, which handles a single incoming request.
+pub(crate) type DynProcedure<R> = Arc<dyn Fn(Invoke<R>, BigIntPolicy) + Send + Sync>;
+
+/// The key of the argument of a procedure registered with `Router::procedure`.
+pub(crate) const DYN_ARGS_KEY: &str = "args";
+
+/// Create the handler of a procedure registered with `Router::procedure`.
+pub(crate) fn dyn_procedure<R, In, Out, F, Fut>(name: String, handler: F) -> DynProcedure<R>
+where
+    R: Runtime,
+    In: DeserializeOwned + Send + 'static,
+    Out: Serialize + 'static,
+    F: Fn(In) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Out> + Send + 'static,
+{
+    let handler = Arc::new(handler);
+    Arc::new(move |invoke: Invoke<R>, policy: BigIntPolicy| {
+        let handler = handler.clone();
+        let name = name.clone();
+        let Invoke {
+            message, resolver, ..
+        } = invoke;
+
+        #[allow(deprecated)]
+        resolver.respond_async_serialized(async move {
+            // Missing arguments are deserialized from `null`, like tauri does for `Option<T>`'s.
+            let args = match message.payload() {
+                InvokeBody::Json(payload) => payload.get(DYN_ARGS_KEY).unwrap_or(&Value::Null),
+                InvokeBody::Raw(_) => {
+                    return Err(InvokeError::from(format!(
+                        "command `{name}` expected a JSON payload"
+                    )));
+                }
+            };
+            let args: In = bigint::deserialize_value(args, policy).map_err(|err| {
+                InvokeError::from(format!(
+                    "invalid args `{DYN_ARGS_KEY}` for command `{name}`: {err}"
+                ))
+            })?;
+            ValueResponseTag.respond(handler(args).await, policy)
+        });
+    })
+}
+
+/// The exported function of a procedure registered with `Router::procedure`, all referenced types will be added to `types`.
+pub(crate) fn dyn_function<In: Type, Out: Type>(
+    path_prefix: &str,
+    name: &str,
+    types: &mut Types,
+) -> Function {
+    // `Function` can't be constructed outside of specta, the arguments and the result are replaced.
+    #[specta::specta]
+    fn procedure() {}
+
+    // There is no trait, the path is used to name the types of the procedure.
+    let trait_name = match path_prefix {
+        "" => "Procedures".to_string(),
+        path => path.replace('.', "_").to_upper_camel_case(),
+    };
+
+    let mut function = specta::function::fn_datatype!(procedure)(types);
+    function.name = format!("{trait_name}_taurpc_fn__{name}").into();
+    // `()` is deserialized from a missing `args`, so it can be omitted like a trailing `Option<T>`.
+    let args = match In::definition(types) {
+        DataType::Tuple(tuple) if tuple.elements.is_empty() => {
+            DataType::Nullable(Box::new(DataType::Tuple(tuple)))
+        }
+        args => args,
+    };
+    function.args = vec![(DYN_ARGS_KEY.into(), args)];
+    function.result = Some(Out::definition(types));
+    function
+}
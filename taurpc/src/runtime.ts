@@ -0,0 +1,436 @@
+import * as tauriCore from '@tauri-apps/api/core'
+import * as tauriEvent from '@tauri-apps/api/event'
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+type RoutesLayer = { [key: string]: (...args: any) => unknown }
+type NestedRoutes = {
+  [route: string]: RoutesLayer | NestedRoutes
+}
+type Router = NestedRoutes & { ''?: RoutesLayer }
+// The listeners of the events of every path, events can't be called.
+type RouterEvents = { [path: string]: RoutesLayer }
+
+type InvokeFn<
+  TRoutes extends RoutesLayer,
+  TProc extends string,
+> = TRoutes[TProc]
+
+// Helper type to swap the return type of functions returning Promise<T> to void
+type SwapReturnTypeToVoid<T> = T extends (...args: infer A) => Promise<unknown>
+  ? (...args: A) => void
+  : never
+
+type ListenerFn<
+  TRoutes extends RoutesLayer,
+  TProc extends string,
+> = SwapReturnTypeToVoid<TRoutes[TProc]>
+
+// Bindings without `RouterEvents` can call and listen to every procedure.
+type InvokeLayer<
+  TRoutes extends RoutesLayer,
+  TProcedures extends Extract<keyof TRoutes, string> = Extract<
+    keyof TRoutes,
+    string
+  >,
+> = {
+  [TProc in TProcedures]: InvokeFn<TRoutes, TProc> & {
+    on: (
+      listener: ListenerFn<TRoutes, TProc>,
+    ) => Promise<tauriEvent.UnlistenFn>
+  }
+}
+
+type ListenLayer<TEvents> = {
+  [TEvent in Extract<keyof TEvents, string>]: {
+    on: (listener: TEvents[TEvent]) => Promise<tauriEvent.UnlistenFn>
+  }
+}
+
+type RoutesAt<TRoutes, TPath> = TPath extends keyof TRoutes
+  ? TRoutes[TPath] extends RoutesLayer ? TRoutes[TPath] : Record<never, never>
+  : Record<never, never>
+
+type PathLayer<
+  TRouter extends Router,
+  TEvents extends RouterEvents | undefined,
+  TPath,
+> = TEvents extends RouterEvents
+  ? RoutesAt<TRouter, TPath> & ListenLayer<RoutesAt<TEvents, TPath>>
+  : RoutesAt<TRouter, TPath> extends infer TRoutes extends RoutesLayer
+    ? InvokeLayer<TRoutes>
+  : never
+
+// The layer of every path, keyed by the full path.
+type PathLayers<
+  TRouter extends Router,
+  TEvents extends RouterEvents | undefined,
+> = {
+  [TPath in Extract<keyof TRouter | keyof TEvents, string>]: PathLayer<
+    TRouter,
+    TEvents,
+    TPath
+  >
+}
+
+type NestPath<TPath extends string, TLayer> = TPath extends
+  `${infer A}.${infer B}` ? { [K in A]: NestPath<B, TLayer> }
+  : { [K in TPath]: TLayer }
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+type UnionToIntersection<U> = (U extends any ? (k: U) => void : never) extends
+  ((k: infer I) => void) ? I : never
+
+type ConvertToNestedObject<TLayers> = UnionToIntersection<
+  {
+    [TPath in Extract<keyof TLayers, string>]: NestPath<TPath, TLayers[TPath]>
+  }[Extract<keyof TLayers, string>]
+>
+
+type TauRpcProxy<
+  TRouter extends Router,
+  TEvents extends RouterEvents | undefined,
+  TLayers = PathLayers<TRouter, TEvents>,
+> =
+  & (TLayers extends { '': infer TRoot } ? TRoot : object)
+  & ConvertToNestedObject<Omit<TLayers, ''>>
+
+type Payload = {
+  event_name: string
+  event: { proc_name: string; input_type: unknown }
+}
+type ListenFn = (args: unknown) => void
+type ArgsMap = Record<string, Record<string, string[]>>
+
+type Transform =
+  | 'bigint'
+  | 'date'
+  | 'bytes'
+  | { object: Record<string, Transform> }
+  | { array: Transform }
+  | { record: Transform }
+  | { map: Transform | null; key: Transform | 'number' | null }
+  | { tuple: (Transform | null)[] }
+  | { union: Transform[] }
+  | { ref: string }
+type ProcedureTransforms = {
+  args: (Transform | null)[]
+  output: Transform | null
+}
+type Transforms = {
+  types: Record<string, Transform>
+  procedures: Record<string, Record<string, ProcedureTransforms>>
+}
+type TransformDirection = 'serialize' | 'deserialize'
+// The deprecation notes of the procedures, `null` when there is no note.
+type Deprecations = Record<string, Record<string, string | null>>
+// The fields of the procedures that take their arguments as a single object.
+type ObjectArgs = Record<string, Record<string, string[]>>
+
+const TAURPC_EVENT_NAME = 'TauRpc_event'
+
+const createTauRPCProxy = <
+  TRouter extends Router,
+  TEvents extends RouterEvents | undefined = undefined,
+>(
+  args: Record<string, string>,
+  transforms?: Transforms,
+  deprecations?: Deprecations,
+  object_args?: ObjectArgs,
+) => {
+  const args_map = parseArgsMap(args)
+  return nestedProxy(
+    args_map,
+    transforms,
+    deprecations,
+    object_args,
+  ) as TauRpcProxy<TRouter, TEvents>
+}
+
+const nestedProxy = (
+  args_maps: ArgsMap,
+  transforms?: Transforms,
+  deprecations?: Deprecations,
+  object_args?: ObjectArgs,
+  path: string[] = [],
+) => {
+  return new window.Proxy({}, {
+    get(_target, p, _receiver): object {
+      const method_name = p.toString()
+      const nested_path = [...path, method_name]
+      const args_map = args_maps[path.join('.')]
+      const path_transforms = transforms?.procedures[path.join('.')]
+      if (method_name === 'then') return {}
+
+      if (args_map && method_name in args_map) {
+        return new window.Proxy(() => {
+          // Empty fn
+        }, {
+          get: (_target, prop, _receiver) => {
+            if (prop !== 'on') return
+
+            const event_name = nested_path.join('.')
+            return async (listener: (args: unknown) => void) => {
+              return await tauriEvent.listen(
+                TAURPC_EVENT_NAME,
+                createEventHandlder(
+                  event_name,
+                  listener,
+                  args_map,
+                  transforms,
+                  path_transforms,
+                ),
+              )
+            }
+          },
+          apply(_target, _thisArg, args) {
+            const deprecation = deprecations?.[path.join('.')]?.[method_name]
+            if (deprecation !== undefined) {
+              warnDeprecated(nested_path.join('.'), deprecation)
+            }
+
+            // Spread the fields of the object over the positional arguments.
+            const fields = object_args?.[path.join('.')]?.[method_name]
+            if (fields) {
+              const object = (args[0] ?? {}) as Record<string, unknown>
+              args = fields.map((field) => object[field])
+            }
+
+            return handleProxyCall(
+              nested_path.join('.'),
+              args,
+              // eslint-disable-next-line @typescript-eslint/no-non-null-assertion
+              args_map[method_name]!,
+              transforms,
+              path_transforms?.[method_name],
+            )
+          },
+        })
+      } else if (
+        nested_path.join('.') in args_maps
+        || Object.keys(args_maps).some(path =>
+          path.startsWith(`${nested_path.join('.')}.`)
+        )
+      ) {
+        return nestedProxy(
+          args_maps,
+          transforms,
+          deprecations,
+          object_args,
+          nested_path,
+        )
+      } else {
+        throw new Error(`'${nested_path.join('.')}' not found`)
+      }
+    },
+  })
+}
+
+const handleProxyCall = async (
+  path: string,
+  args: unknown[],
+  procedure_args: string[],
+  transforms?: Transforms,
+  procedure_transforms?: ProcedureTransforms,
+) => {
+  const args_object: Record<string, unknown> = {}
+
+  for (let idx = 0; idx < procedure_args.length; idx++) {
+    const arg_name = procedure_args[idx]
+    if (!arg_name) throw new Error('Received invalid arguments')
+
+    const arg = args[idx]
+    if (typeof arg == 'function') {
+      const channel = new tauriCore.Channel()
+      channel.onmessage = arg as typeof channel.onmessage
+      args_object[arg_name] = channel
+    } else {
+      const transform = procedure_transforms?.args[idx]
+      args_object[arg_name] = transforms && transform
+        ? transformValue(arg, transform, transforms, 'serialize')
+        : arg
+    }
+  }
+
+  const response = await tauriCore.invoke(
+    `TauRPC__${path}`,
+    args_object,
+  )
+
+  const transform = procedure_transforms?.output
+  return transforms && transform
+    ? transformValue(response, transform, transforms, 'deserialize')
+    : response
+}
+
+const warned_deprecations = new Set<string>()
+
+// Warn once for every deprecated procedure that is called.
+const warnDeprecated = (path: string, note: string | null) => {
+  if (warned_deprecations.has(path)) return
+  warned_deprecations.add(path)
+
+  console.warn(`'${path}' is deprecated${note ? `: ${note}` : ''}`)
+}
+
+const INTEGER_PATTERN = /^-?\d+$/
+
+// Convert the values described by the transform, leave the rest as-is.
+const transformValue = (
+  value: unknown,
+  transform: Transform,
+  transforms: Transforms,
+  direction: TransformDirection,
+): unknown => {
+  if (value === null || value === undefined) return value
+
+  const recurse = (value: unknown, transform: Transform | null | undefined) =>
+    transform ? transformValue(value, transform, transforms, direction) : value
+
+  if (transform === 'bigint') {
+    if (direction === 'serialize') {
+      return typeof value === 'bigint' ? value.toString() : value
+    }
+    if (typeof value === 'number' && Number.isInteger(value)) {
+      return BigInt(value)
+    }
+    return typeof value === 'string' && INTEGER_PATTERN.test(value)
+      ? BigInt(value)
+      : value
+  }
+  if (transform === 'date') {
+    if (direction === 'serialize') {
+      return value instanceof Date ? value.toISOString() : value
+    }
+    return typeof value === 'string' || typeof value === 'number'
+      ? new Date(value)
+      : value
+  }
+  if (transform === 'bytes') {
+    if (direction === 'serialize') {
+      return value instanceof Uint8Array ? Array.from(value) : value
+    }
+    return Array.isArray(value) ? Uint8Array.from(value as number[]) : value
+  }
+  if ('ref' in transform) return recurse(value, transforms.types[transform.ref])
+  if ('union' in transform) {
+    return transform.union.reduce((value, t) => recurse(value, t), value)
+  }
+  if ('array' in transform) {
+    return Array.isArray(value)
+      ? value.map((item) => recurse(item, transform.array))
+      : value
+  }
+  if ('tuple' in transform) {
+    return Array.isArray(value)
+      ? value.map((item, idx) => recurse(item, transform.tuple[idx]))
+      : value
+  }
+
+  if ('map' in transform) {
+    // Object keys are always strings, numeric keys are converted back.
+    const key_transform = transform.key === 'number' ? null : transform.key
+    if (direction === 'serialize') {
+      if (!(value instanceof Map)) return value
+      return Object.fromEntries(
+        Array.from(value).map((
+          [key, item],
+        ) => [
+          String(recurse(key, key_transform)),
+          recurse(item, transform.map),
+        ]),
+      )
+    }
+    if (typeof value !== 'object' || Array.isArray(value)) return value
+    return new Map(
+      Object.entries(value).map((
+        [key, item],
+      ) => [
+        transform.key === 'number' ? Number(key) : recurse(key, key_transform),
+        recurse(item, transform.map),
+      ]),
+    )
+  }
+
+  if (typeof value !== 'object' || Array.isArray(value)) return value
+  if ('record' in transform) {
+    return Object.fromEntries(
+      Object.entries(value).map((
+        [key, item],
+      ) => [key, recurse(item, transform.record)]),
+    )
+  }
+
+  const result: Record<string, unknown> = { ...value }
+  for (const [key, t] of Object.entries(transform.object)) {
+    if (key in result) result[key] = recurse(result[key], t)
+  }
+  return result
+}
+
+const createEventHandlder = (
+  event_name: string,
+  listener: ListenFn,
+  args_map: ArgsMap[string],
+  transforms?: Transforms,
+  path_transforms?: Transforms['procedures'][string],
+): tauriEvent.EventCallback<Payload> => {
+  return (event) => {
+    if (event_name !== event.payload.event_name) return
+
+    const path_segments = event.payload.event_name.split('.')
+    const ev = path_segments.pop()
+    if (!ev) return
+
+    const args = args_map[ev]
+    if (!args) return
+
+    let input = event.payload.event.input_type
+    const args_transforms = path_transforms?.[ev]?.args
+    if (transforms && args_transforms) {
+      input = args.length === 1
+        ? args_transforms[0]
+          ? transformValue(input, args_transforms[0], transforms, 'deserialize')
+          : input
+        : transformValue(
+          input,
+          { tuple: args_transforms },
+          transforms,
+          'deserialize',
+        )
+    }
+
+    if (args.length === 1) {
+      listener(input)
+    } else if (Array.isArray(input)) {
+      const _ = (listener as ((...args: unknown[]) => void))(
+        ...input as unknown[],
+      )
+    } else {
+      listener(input)
+    }
+  }
+}
+
+const parseArgsMap = (args: Record<string, string>) => {
+  const args_map: Record<string, Record<string, string[]>> = {}
+  Object.entries(args).map(
+    ([path, args]) => {
+      args_map[path] = JSON.parse(args) as Record<string, string[]>
+    },
+  )
+
+  return args_map
+}
+
+// #region exports (kept at the top level when inlined in the bindings)
+export type InferCommandOutput<
+  TRouter extends object,
+  TPath extends keyof TRouter,
+  TCommand extends keyof TRouter[TPath],
+> = TRouter[TPath][TCommand] extends (...args: never) => infer TOutput
+  ? Awaited<TOutput>
+  : unknown
+// #endregion
+
+export type { Deprecations, ObjectArgs, Transforms }
+export { createTauRPCProxy }
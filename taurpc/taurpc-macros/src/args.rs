@@ -1,6 +1,10 @@
-use proc_macro2::TokenStream as TokenStream2;
+use crate::attrs::doc_comments;
+
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
 use quote::{ToTokens, quote_spanned};
-use syn::{Ident, Pat, PatType, Type, ext::IdentExt, spanned::Spanned};
+use syn::{
+    Generics, Ident, LitStr, Pat, PatType, Type, ext::IdentExt, parse_quote, spanned::Spanned,
+};
 
 // TODO: Add raw request??
 const RESERVED_ARGS: &[&str] = &["window", "state", "app_handle", "webview_window"];
@@ -9,7 +13,12 @@ pub(crate) struct Arg {
     pat: PatType,
     /// Should this argument be skipped in the generated types.
     pub skip_type: bool,
-    // alias: String
+    /// The doc comments of the argument, rustc doesn't allow them on function arguments.
+    pub docs: Vec<String>,
+    /// Fall back to `Default::default()` when the argument is omitted, `#[taurpc(default)]`.
+    pub default: bool,
+    /// The key of the argument sent by the frontend, `#[taurpc(rename = "...")]` or `rename_all` on the trait.
+    pub rename: Option<String>,
 }
 
 impl Arg {
@@ -24,18 +33,69 @@ impl Arg {
     pub fn span(&self) -> proc_macro2::Span {
         self.pat.span()
     }
+
+    /// The argument as it is exported, arguments with a default are exported as an `Option<T>`.
+    pub fn exported(&self) -> PatType {
+        let mut pat = self.pat.clone();
+        if self.default {
+            let ty = &pat.ty;
+            pat.ty = parse_quote!(::core::option::Option<#ty>);
+        }
+        pat
+    }
 }
 
-impl From<PatType> for Arg {
-    fn from(mut pat: PatType) -> Self {
+impl TryFrom<PatType> for Arg {
+    type Error = syn::Error;
+
+    fn try_from(mut pat: PatType) -> syn::Result<Self> {
         // Skip this argument in type generation based on our defined reserved argument names.
         let mut skip_type = matches!(
             pat.pat.as_ref(),
             Pat::Ident(pat_ident) if RESERVED_ARGS.iter().any(|&s| pat_ident.ident == s)
         );
 
+        let docs = doc_comments(&pat.attrs);
+        let mut default = false;
+        let mut rename = None;
+        for attr in pat
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("taurpc"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    default = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    let lit: LitStr = value.parse()?;
+
+                    // The name is also used for the argument in the TypeScript bindings.
+                    let name = lit.value();
+                    let mut chars = name.chars();
+                    let is_identifier = chars
+                        .next()
+                        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+                        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+                    if !is_identifier {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "rename should be a valid identifier",
+                        ));
+                    }
+
+                    rename = Some(name);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute"))
+                }
+            })?;
+        }
+
         // These reserved args can also be used when they are tagged with an attribute, for
         // example `fn my_command(#[app_handle] h: AppHandle<impl Runtime>)`.
+        // Doc comments are removed as well, they are exported with `TauRpcHandler::docs`.
         pat.attrs = pat
             .attrs
             .into_iter()
@@ -45,11 +105,17 @@ impl From<PatType> for Arg {
                     return false;
                 }
 
-                true
+                !attr.path().is_ident("doc") && !attr.path().is_ident("taurpc")
             })
             .collect::<Vec<_>>();
 
-        Self { pat, skip_type }
+        Ok(Self {
+            pat,
+            skip_type,
+            docs,
+            default,
+            rename,
+        })
     }
 }
 
@@ -63,14 +129,22 @@ impl ToTokens for Arg {
 pub(crate) fn parse_args(
     args: &[Arg],
     message: &Ident,
+    policy: &Ident,
     proc_ident: &Ident,
+    generics: &Generics,
 ) -> syn::Result<Vec<TokenStream2>> {
     args.iter()
-        .map(|arg| parse_arg(arg, message, proc_ident))
+        .map(|arg| parse_arg(arg, message, policy, proc_ident, generics))
         .collect()
 }
 
-fn parse_arg(arg: &Arg, message: &Ident, proc_ident: &Ident) -> syn::Result<TokenStream2> {
+fn parse_arg(
+    arg: &Arg,
+    message: &Ident,
+    policy: &Ident,
+    proc_ident: &Ident,
+    generics: &Generics,
+) -> syn::Result<TokenStream2> {
     let key = parse_arg_key(arg)?;
 
     // catch self arguments that use FnArg::Typed syntax
@@ -82,21 +156,66 @@ fn parse_arg(arg: &Arg, message: &Ident, proc_ident: &Ident) -> syn::Result<Toke
     }
 
     let arg_span = arg.span();
-    // this way tauri knows how to deserialize the different types of the args
-    Ok(
-        quote_spanned!(arg_span=> ::tauri::ipc::CommandArg::from_command(
-          ::tauri::ipc::CommandItem {
-            name: stringify!(#proc_ident),
-            key: #key,
-            message: &#message,
-            acl: &None,
-            plugin: None,
-          }
-        )),
-    )
+    let item = quote_spanned!(arg_span=> ::tauri::ipc::CommandItem {
+        name: stringify!(#proc_ident),
+        key: #key,
+        message: &#message,
+        acl: &None,
+        plugin: None,
+    });
+
+    // The type can't be named inside of the handler when it uses the generics of the method.
+    if arg.skip_type || !is_nameable(&arg.ty().to_token_stream(), generics) {
+        // this way tauri knows how to deserialize the different types of the args
+        return Ok(quote_spanned!(arg_span=> ::tauri::ipc::CommandArg::from_command(#item)));
+    }
+
+    // Deserializable args also accept 64 and 128-bit integers that are sent as strings when the router
+    // doesn't use `BigIntExport::Number`, other args fall back to tauri's `CommandArg`.
+    let ty = arg.ty();
+    if arg.default {
+        // Missing arguments are deserialized as `None` by tauri.
+        return Ok(quote_spanned!(arg_span=> {
+            #[allow(unused_imports)]
+            use taurpc::private::{CommandArgKind as _, DeserializeArgKind as _};
+            (&::std::marker::PhantomData::<::core::option::Option<#ty>>)
+                .taurpc_arg_kind()
+                .extract(|| #item, #policy)
+                .map(::core::option::Option::unwrap_or_default)
+        }));
+    }
+
+    Ok(quote_spanned!(arg_span=> {
+        #[allow(unused_imports)]
+        use taurpc::private::{CommandArgKind as _, DeserializeArgKind as _};
+        (&::std::marker::PhantomData::<#ty>).taurpc_arg_kind().extract(|| #item, #policy)
+    }))
+}
+
+/// Check that the type doesn't contain `impl Trait`, named lifetimes or generic parameters.
+pub(crate) fn is_nameable(tokens: &TokenStream2, generics: &Generics) -> bool {
+    tokens.clone().into_iter().all(|token| match token {
+        TokenTree::Group(group) => is_nameable(&group.stream(), generics),
+        TokenTree::Ident(ident) => {
+            ident != "impl"
+                && ident != "Self"
+                && !generics.type_params().any(|param| param.ident == ident)
+                && !generics.const_params().any(|param| param.ident == ident)
+        }
+        TokenTree::Punct(punct) => punct.as_char() != '\'',
+        TokenTree::Literal(_) => true,
+    })
 }
 
 pub(crate) fn parse_arg_key(arg: &Arg) -> Result<String, syn::Error> {
+    match &arg.rename {
+        Some(rename) => Ok(rename.clone()),
+        None => parse_arg_name(arg),
+    }
+}
+
+/// The name of the argument without `#[taurpc(rename)]`, this is the name of the argument in the exported function.
+pub(crate) fn parse_arg_name(arg: &Arg) -> Result<String, syn::Error> {
     // we only support patterns that allow us to extract some sort of keyed identifier
     match arg.pat() {
         Pat::Ident(arg) => Ok(arg.ident.unraw().to_string()),
@@ -109,3 +228,90 @@ pub(crate) fn parse_arg_key(arg: &Arg) -> Result<String, syn::Error> {
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proc::parse_fn_args;
+    use syn::ItemFn;
+
+    fn parse(item: ItemFn) -> syn::Result<Vec<Arg>> {
+        parse_fn_args(item.sig.inputs, &item.sig.generics)
+    }
+
+    fn tokens(tokens: impl ToTokens) -> String {
+        tokens.to_token_stream().to_string()
+    }
+
+    #[test]
+    fn default_args() {
+        let args = parse(parse_quote! {
+            fn search(query: String, #[taurpc(default)] exact: bool) {}
+        })
+        .unwrap();
+
+        assert!(!args[0].default);
+        assert!(args[1].default);
+        assert_eq!(
+            tokens(args[1].exported()),
+            tokens(quote::quote!(exact: ::core::option::Option<bool>))
+        );
+        // The attribute isn't allowed by rustc, it's removed from the argument.
+        assert_eq!(tokens(&args[1]), tokens(quote::quote!(exact: bool)));
+    }
+
+    #[test]
+    fn default_args_need_a_concrete_type() {
+        assert!(
+            parse(parse_quote! {
+                fn save<T>(#[taurpc(default)] item: T) {}
+            })
+            .is_err()
+        );
+        assert!(
+            parse(parse_quote! {
+                fn save(#[taurpc(default)] item: impl Default) {}
+            })
+            .is_err()
+        );
+        assert!(
+            parse(parse_quote! {
+                fn save<R: Runtime>(#[taurpc(default)] app_handle: AppHandle<R>) {}
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn renamed_args() {
+        let args = parse(parse_quote! {
+            fn search(r#type: String, #[taurpc(rename = "maxResults")] limit: u32) {}
+        })
+        .unwrap();
+
+        let keys = args
+            .iter()
+            .map(|arg| parse_arg_key(arg).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["type", "maxResults"]);
+        assert_eq!(parse_arg_name(&args[1]).unwrap(), "limit");
+        assert_eq!(tokens(&args[1]), tokens(quote::quote!(limit: u32)));
+    }
+
+    #[test]
+    fn rename_should_be_an_identifier() {
+        for rename in ["max-results", "1st", ""] {
+            let item: ItemFn = syn::parse_str(&format!(
+                r#"fn search(#[taurpc(rename = "{rename}")] limit: u32) {{}}"#
+            ))
+            .unwrap();
+            assert!(parse(item).is_err(), "{rename}");
+        }
+        assert!(
+            parse(parse_quote! {
+                fn search(#[taurpc(rename = "$limit_2")] limit: u32) {}
+            })
+            .is_ok()
+        );
+    }
+}
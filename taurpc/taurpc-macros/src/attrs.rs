@@ -1,16 +1,42 @@
 use super::extend_errors;
 use proc_macro2::Ident;
 use syn::{
-    Attribute, Expr, Lit, LitStr, MetaNameValue, Token,
-    parse::{Parse, ParseStream},
+    Attribute, Expr, Lit, LitBool, LitStr, MetaNameValue, Path, Token,
+    parse::{Parse, ParseStream, Parser},
     spanned::Spanned,
 };
+use taurpc_rename::RenameRule;
 
 /// Attributes added on the procedures trait itself, `#[taurpc::procedures( ... )]`.
 #[derive(Debug, Default)]
 pub struct ProceduresAttrs {
     pub event_trigger_ident: Option<Ident>,
     pub path: String,
+    pub args: ArgsStyle,
+    pub rename_all: Option<RenameRule>,
+}
+
+/// How the arguments of a procedure are passed in the generated client, `#[taurpc(args = "...")]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ArgsStyle {
+    /// `api.procedure(arg1, arg2)`
+    #[default]
+    Positional,
+    /// `api.procedure({ arg1, arg2 })`
+    Object,
+}
+
+impl ArgsStyle {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "positional" => Ok(Self::Positional),
+            "object" => Ok(Self::Object),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "args should be \"positional\" or \"object\"",
+            )),
+        }
+    }
 }
 
 impl Parse for ProceduresAttrs {
@@ -66,6 +92,38 @@ impl Parse for ProceduresAttrs {
                         syn::Error::new(meta.path.span(), "path should be a str")
                     );
                 }
+            } else if meta.path.is_ident("rename_all") {
+                match meta.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(ref str),
+                        ..
+                    }) => match rename_rule(str) {
+                        Ok(rule) => result.rename_all = Some(rule),
+                        Err(e) => extend_errors!(errors, e),
+                    },
+                    _ => {
+                        extend_errors!(
+                            errors,
+                            syn::Error::new(meta.value.span(), "rename_all should be a str")
+                        );
+                    }
+                }
+            } else if meta.path.is_ident("args") {
+                match meta.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(ref str),
+                        ..
+                    }) => match ArgsStyle::from_lit(str) {
+                        Ok(args) => result.args = args,
+                        Err(e) => extend_errors!(errors, e),
+                    },
+                    _ => {
+                        extend_errors!(
+                            errors,
+                            syn::Error::new(meta.value.span(), "args should be a str")
+                        );
+                    }
+                }
             } else {
                 extend_errors!(
                     errors,
@@ -80,6 +138,63 @@ impl Parse for ProceduresAttrs {
     }
 }
 
+/// Attributes added on shared types, `#[taurpc::ipc_type( ... )]`.
+pub struct IpcTypeAttrs {
+    /// Derive `Clone`, opt out with `clone = false`.
+    pub clone: bool,
+    /// Extra derives, `derive(Debug, PartialEq)`.
+    pub derives: Vec<Path>,
+    /// Forwarded to `#[serde(rename_all = "...")]`, so serde validates it.
+    pub rename_all: Option<LitStr>,
+    /// Skip named `Option` fields when they are `None`, `skip_none`.
+    pub skip_none: bool,
+}
+
+impl Parse for IpcTypeAttrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut result = Self {
+            clone: true,
+            derives: Vec::new(),
+            rename_all: None,
+            skip_none: false,
+        };
+
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("clone") {
+                let value: LitBool = meta.value()?.parse()?;
+                result.clone = value.value;
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|derive| {
+                    result.derives.push(derive.path);
+                    Ok(())
+                })
+            } else if meta.path.is_ident("rename_all") {
+                result.rename_all = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("skip_none") {
+                result.skip_none = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        });
+        parser.parse2(input.parse()?)?;
+
+        Ok(result)
+    }
+}
+
+/// Parse the naming convention of `#[taurpc::procedures(rename_all = "...")]`.
+fn rename_rule(lit: &LitStr) -> syn::Result<RenameRule> {
+    RenameRule::from_name(&lit.value()).ok_or_else(|| {
+        syn::Error::new(
+            lit.span(),
+            "rename_all should be one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\" or \"SCREAMING_SNAKE_CASE\"",
+        )
+    })
+}
+
 /// Attributes defined on methods inside a procedures trait.
 /// Parse the attributes to make sure they are defined in the correct way, like `#[taurpc( ... )]`, accumulate
 /// all errors and then display them together with `extend_errors!()`.  
@@ -88,6 +203,8 @@ pub struct MethodAttrs {
     pub(crate) skip: bool,
     pub(crate) alias: Option<String>,
     pub(crate) is_event: bool,
+    /// Overrides the argument style of the trait for this method.
+    pub(crate) args: Option<ArgsStyle>,
     pub(crate) comments: Vec<String>,
     /// Attributes to forward to the generated code (e.g., #[allow(...)])
     pub(crate) passthrough_attrs: Vec<Attribute>,
@@ -99,6 +216,7 @@ impl Parse for MethodAttrs {
         let attrs = input.call(Attribute::parse_outer)?;
 
         let mut errors = Ok(());
+        let mut args_span = None;
 
         for attr in attrs {
             if attr.path().is_ident("doc") {
@@ -129,6 +247,13 @@ impl Parse for MethodAttrs {
 
                     res.alias = Some(alias.value());
                     Ok(())
+                } else if meta.path.is_ident("args") {
+                    args_span = Some(meta.path.span());
+                    let value = meta.value()?;
+                    let args: LitStr = value.parse()?;
+
+                    res.args = Some(ArgsStyle::from_lit(&args)?);
+                    Ok(())
                 } else {
                     Err(meta.error("unsupported attribute"))
                 }
@@ -137,8 +262,82 @@ impl Parse for MethodAttrs {
             };
         }
 
+        if let (true, Some(ArgsStyle::Object), Some(span)) = (res.is_event, res.args, args_span) {
+            extend_errors!(
+                errors,
+                syn::Error::new(span, "events can't take their arguments as an object")
+            );
+        }
+
         errors?;
 
         Ok(res)
     }
 }
+
+/// Collect the doc comments from the attributes, one line per `#[doc = "..."]`.
+pub(crate) fn doc_comments(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }),
+                ..
+            }) => Some(lit_str.value().trim().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn procedures_args_style() {
+        let attrs: ProceduresAttrs = syn::parse_quote!(path = "api", args = "object");
+        assert_eq!(attrs.path, "api");
+        assert_eq!(attrs.args, ArgsStyle::Object);
+
+        let attrs: ProceduresAttrs = syn::parse_quote!(path = "api");
+        assert_eq!(attrs.args, ArgsStyle::Positional);
+
+        assert!(syn::parse_str::<ProceduresAttrs>(r#"args = "named""#).is_err());
+        assert!(syn::parse_str::<ProceduresAttrs>("args = object").is_err());
+    }
+
+    #[test]
+    fn method_args_style() {
+        let attrs: MethodAttrs = syn::parse_quote!(#[taurpc(args = "object")]);
+        assert_eq!(attrs.args, Some(ArgsStyle::Object));
+
+        let attrs: MethodAttrs = syn::parse_quote!(#[taurpc(args = "positional")]);
+        assert_eq!(attrs.args, Some(ArgsStyle::Positional));
+
+        assert!(syn::parse_str::<MethodAttrs>(r#"#[taurpc(event, args = "object")]"#).is_err());
+    }
+
+    #[test]
+    fn rename_rules() {
+        let cases = [
+            ("lowercase", "getuser"),
+            ("UPPERCASE", "GETUSER"),
+            ("PascalCase", "GetUser"),
+            ("camelCase", "getUser"),
+            ("snake_case", "get_user"),
+            ("SCREAMING_SNAKE_CASE", "GET_USER"),
+        ];
+        for (rule, expected) in cases {
+            let attrs: ProceduresAttrs =
+                syn::parse_str(&format!(r#"rename_all = "{rule}""#)).unwrap();
+            assert_eq!(attrs.rename_all.unwrap().apply("getUser"), expected);
+        }
+
+        assert!(syn::parse_str::<ProceduresAttrs>(r#"rename_all = "kebab-case""#).is_err());
+    }
+}
@@ -1,16 +1,18 @@
-use crate::args::{parse_arg_key, parse_args};
+use crate::args::{parse_arg_key, parse_arg_name, parse_args};
+use crate::attrs::{ArgsStyle, doc_comments};
 use crate::{method_fut_ident, proc::IpcMethod};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, format_ident, quote, quote_spanned};
 use std::collections::BTreeMap;
-use syn::{Attribute, Generics, Ident, Type, Visibility, parse_quote};
+use syn::{Attribute, Generics, Ident, ReturnType, Type, Visibility, parse_quote};
 
 pub struct ProceduresGenerator<'a> {
     pub trait_ident: &'a Ident,
     pub handler_ident: &'a Ident,
     pub event_trigger_ident: &'a Ident,
     pub path_prefix: String,
+    pub args_style: ArgsStyle,
     pub inputs_ident: &'a Ident,
     pub outputs_ident: &'a Ident,
     pub output_futures_ident: &'a Ident,
@@ -23,6 +25,45 @@ pub struct ProceduresGenerator<'a> {
 }
 
 impl ProceduresGenerator<'_> {
+    /// The type parameters of the trait, `T` in `trait Repository<T>`.
+    fn type_params(&self) -> Vec<&Ident> {
+        self.generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect()
+    }
+
+    /// Marks the type parameters of the trait as used, `None` when the trait isn't generic.
+    fn phantom(&self) -> Option<TokenStream2> {
+        let params = self.type_params();
+        (!params.is_empty()).then(|| quote!(::core::marker::PhantomData<fn() -> (#( #params, )*)>))
+    }
+
+    /// The type parameters are sent over IPC and exported, so they need the same bounds as the types
+    /// of `#[taurpc::ipc_type]`. Events are cloned when they are sent, so they add `Clone` to `extra_bounds`.
+    fn where_clause(&self, extra_bounds: TokenStream2) -> TokenStream2 {
+        let params = self.type_params();
+        let predicates = self
+            .generics
+            .where_clause
+            .as_ref()
+            .map(|where_clause| &where_clause.predicates);
+        if params.is_empty() && predicates.is_none() {
+            return quote!();
+        }
+
+        quote! {
+            where
+                #( #params: taurpc::serde::Serialize
+                    + taurpc::serde::de::DeserializeOwned
+                    + taurpc::specta::Type
+                    + Send
+                    + 'static
+                    #extra_bounds, )*
+                #predicates
+        }
+    }
+
     fn procedures_trait(&self) -> TokenStream2 {
         let &ProceduresGenerator {
             trait_ident,
@@ -35,6 +76,10 @@ impl ProceduresGenerator<'_> {
             alias_method_idents,
             ..
         } = self;
+        let is_generic = self.phantom().is_some();
+        let params = self.type_params();
+        let where_clause = &generics.where_clause;
+        let phantom_init = is_generic.then(|| quote!(, _params: ::core::marker::PhantomData));
 
         let fn_types = alias_method_idents.iter().zip(methods).map(
             |(
@@ -47,10 +92,23 @@ impl ProceduresGenerator<'_> {
                     ..
                 },
             )| {
-                let args = args.iter().filter(|&arg| !arg.skip_type);
+                let args = args
+                    .iter()
+                    .filter(|&arg| !arg.skip_type)
+                    .map(|arg| arg.exported());
                 let fn_ident = fn_ident(trait_ident, ident);
                 let passthrough_attrs = &attrs.passthrough_attrs;
 
+                // The types of a generic trait are only known in the handler, see `collect_fn_types`.
+                if is_generic {
+                    return quote_spanned! {*span=>
+                        #( #passthrough_attrs )*
+                        #[specta::specta]
+                        #[allow(non_snake_case)]
+                        fn #fn_ident() {}
+                    };
+                }
+
                 quote_spanned! {*span=>
                     #( #passthrough_attrs )*
                     #[specta::specta]
@@ -95,12 +153,12 @@ impl ProceduresGenerator<'_> {
 
         quote! {
             #( #attrs )*
-            #vis trait #trait_ident #generics: Sized {
+            #vis trait #trait_ident #generics: Sized #where_clause {
                 #( #types_and_fns )*
 
                 /// Returns handler used for incoming requests and type generation.
-                fn into_handler(self) -> #handler_ident<Self> {
-                    #handler_ident { methods: self }
+                fn into_handler(self) -> #handler_ident<Self #( , #params )*> {
+                    #handler_ident { methods: self #phantom_init }
                 }
             }
 
@@ -112,10 +170,19 @@ impl ProceduresGenerator<'_> {
         let &Self {
             methods,
             vis,
+            generics,
             inputs_ident,
             alias_method_idents,
             ..
         } = self;
+        let where_clause = &generics.where_clause;
+        let phantom = self.phantom().map(|phantom| {
+            quote! {
+                #[doc(hidden)]
+                #[serde(skip)]
+                __TauRpcPhantom(#phantom)
+            }
+        });
 
         let inputs =
             alias_method_idents
@@ -148,8 +215,9 @@ impl ProceduresGenerator<'_> {
             #[derive(taurpc::serde::Serialize, Clone)]
             #[serde(tag = "proc_name", content = "input_type")]
             #[allow(non_camel_case_types)]
-            #vis enum #inputs_ident {
-                #( #inputs ),*
+            #vis enum #inputs_ident #generics #where_clause {
+                #( #inputs, )*
+                #phantom
             }
         }
     }
@@ -158,10 +226,18 @@ impl ProceduresGenerator<'_> {
         let &Self {
             methods,
             vis,
+            generics,
             outputs_ident,
             method_output_types,
             ..
         } = self;
+        let where_clause = &generics.where_clause;
+        let phantom = self.phantom().map(|phantom| {
+            quote! {
+                #[doc(hidden)]
+                __TauRpcPhantom(#phantom)
+            }
+        });
 
         let outputs = methods.iter().zip(method_output_types.iter()).map(
             |(IpcMethod { ident, .. }, output_ty)| {
@@ -171,12 +247,12 @@ impl ProceduresGenerator<'_> {
             },
         );
 
+        // Not serialized, the outputs are sent with the response kinds of `taurpc::private`.
         quote! {
-            #[derive(taurpc::serde::Serialize)]
-            #[serde(tag = "proc_name", content = "output_type")]
             #[allow(non_camel_case_types)]
-            #vis enum #outputs_ident {
-                #( #outputs ),*
+            #vis enum #outputs_ident #generics #where_clause {
+                #( #outputs, )*
+                #phantom
             }
         }
     }
@@ -186,10 +262,22 @@ impl ProceduresGenerator<'_> {
             methods,
             trait_ident,
             vis,
+            generics,
             output_futures_ident,
             outputs_ident,
             ..
         } = self;
+        let params = self.type_params();
+        let param_decls = generics.params.iter().collect::<Vec<_>>();
+        let where_clause = &generics.where_clause;
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let phantom = self.phantom();
+        let phantom_variant = phantom
+            .as_ref()
+            .map(|phantom| quote!(#[doc(hidden)] __TauRpcPhantom(#phantom)));
+        let phantom_arm = phantom
+            .as_ref()
+            .map(|_| quote!(#output_futures_ident::__TauRpcPhantom(_) => unreachable!(),));
 
         let outputs = methods
             .iter()
@@ -200,7 +288,7 @@ impl ProceduresGenerator<'_> {
                 let future_ident = method_fut_ident(ident);
 
                 Some(quote! {
-                    #ident(<P as #trait_ident>::#future_ident)
+                    #ident(<P as #trait_ident #ty_generics>::#future_ident)
                 })
             })
             .collect::<Vec<_>>();
@@ -217,15 +305,18 @@ impl ProceduresGenerator<'_> {
 
         quote! {
             #[allow(non_camel_case_types)]
-            #vis enum #output_futures_ident<P: #trait_ident> {
-                #( #outputs ),*
+            #vis enum #output_futures_ident<P: #trait_ident #ty_generics #( , #param_decls )*> #where_clause {
+                #( #outputs, )*
+                #phantom_variant
             }
 
-            impl<P: #trait_ident> std::future::Future for #output_futures_ident<P> {
-                type Output = #outputs_ident;
+            impl<P: #trait_ident #ty_generics #( , #param_decls )*> std::future::Future
+                for #output_futures_ident<P #( , #params )*> #where_clause
+            {
+                type Output = #outputs_ident #ty_generics;
 
                 fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>)
-                    -> std::task::Poll<#outputs_ident>
+                    -> std::task::Poll<#outputs_ident #ty_generics>
                 {
                     unsafe {
                         match std::pin::Pin::get_unchecked_mut(self) {
@@ -235,6 +326,7 @@ impl ProceduresGenerator<'_> {
                                         .poll(cx)
                                         .map(#outputs_ident::#method_idents),
                             )*
+                            #phantom_arm
                         }
                     }
                 }
@@ -250,13 +342,21 @@ impl ProceduresGenerator<'_> {
             vis,
             alias_method_idents,
             methods,
+            attrs,
+            generics,
             ref path_prefix,
+            ref args_style,
             ..
         } = self;
+        let params = self.type_params();
+        let param_decls = generics.params.iter().collect::<Vec<_>>();
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let where_clause = self.where_clause(quote!());
 
         let invoke = format_ident!("__tauri_invoke__");
         let message = format_ident!("__tauri_message__");
         let resolver = format_ident!("__tauri_resolver__");
+        let policy = format_ident!("__taurpc_policy__");
 
         let procedure_handlers = alias_method_idents.iter().zip(methods.iter()).filter_map(
             |(
@@ -266,22 +366,28 @@ impl ProceduresGenerator<'_> {
                     args,
                     attrs,
                     span,
+                    generics,
                     ..
                 },
             )| {
                 if attrs.is_event {
                     return None;
                 }
-                let args = parse_args(args, &message, ident).unwrap();
+                let args = parse_args(args, &message, &policy, ident, generics).unwrap();
                 let method_call = quote_spanned!(*span=> #trait_ident::#ident(
                     self.methods, #( #args.unwrap() ),*
                 ));
 
                 Some(quote! { stringify!(#proc_name) => {
+                    #[allow(deprecated)]
                     #resolver.respond_async_serialized(async move {
-                        let res = #method_call;
-                        let kind = (&res).async_kind();
-                        kind.future(res).await
+                        #[allow(unused_imports)]
+                        use taurpc::private::{
+                            RawResponseKind as _, RawResultResponseKind as _, ResultResponseKind as _,
+                            ValueResponseKind as _,
+                        };
+                        let res = #method_call.await;
+                        (&&&taurpc::private::ResponseRef(&res)).taurpc_response_kind().respond(res, #policy)
                     });
                 }})
             },
@@ -304,22 +410,120 @@ impl ProceduresGenerator<'_> {
             });
 
         let serialized_args_map = serde_json::to_string(&args_map).unwrap();
+        let event_names = alias_method_idents
+            .iter()
+            .zip(methods)
+            .filter(|(_, IpcMethod { attrs, .. })| attrs.is_event)
+            .map(|(ident, _)| ident.to_string());
+        let object_args_names = alias_method_idents
+            .iter()
+            .zip(methods)
+            .filter(|(_, IpcMethod { attrs, .. })| {
+                !attrs.is_event && attrs.args.unwrap_or(*args_style) == ArgsStyle::Object
+            })
+            .map(|(ident, _)| ident.to_string());
         let fn_names = alias_method_idents
             .iter()
             .map(|ident| fn_ident(trait_ident, ident));
 
-        quote! {
-            #[derive(Clone)]
-            #vis struct #handler_ident<P> {
-                methods: P,
+        // The functions of a generic trait are collected without types, the types of the arguments and
+        // outputs are added here, where the type parameters are known.
+        let fn_types = self.phantom().map(|_| {
+            let functions =
+                methods
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, IpcMethod { args, output, .. })| {
+                        // The arguments are named like `#[specta::specta]` does, the keys are applied by the exporter.
+                        let args = args.iter().filter(|arg| !arg.skip_type).map(|arg| {
+                            let name = parse_arg_name(arg).unwrap();
+                            let ty = arg.exported().ty;
+                            quote! {
+                                <#ty as specta::function::FunctionArg>::to_datatype(types)
+                                    .map(|ty| (#name.into(), ty))
+                            }
+                        });
+                        let result = match output {
+                            ReturnType::Type(_, ty) => quote! {
+                                Some(<#ty as specta::function::FunctionResult<specta::function::FunctionValueMarker>>::to_datatype(types))
+                            },
+                            ReturnType::Default => quote!(None),
+                        };
+
+                        quote! {
+                            functions[#idx].args = ::std::iter::empty() #( .chain(#args) )* .collect();
+                            functions[#idx].result = #result;
+                        }
+                    });
+
+            quote! {
+                #( #functions )*
             }
+        });
+
+        // The doc comments of the arguments are collected here, rustc doesn't allow them on the arguments.
+        let trait_docs = doc_comments(attrs).join("\n");
+        let arg_docs = alias_method_idents.iter().zip(methods).filter_map(
+            |(ident, IpcMethod { args, .. })| {
+                let docs = args
+                    .iter()
+                    .filter(|arg| !arg.skip_type && !arg.docs.is_empty())
+                    .map(|arg| {
+                        let key = parse_arg_key(arg).unwrap();
+                        let docs = arg.docs.join("\n");
+                        quote! { (#key.to_string(), #docs.to_string()) }
+                    })
+                    .collect::<Vec<_>>();
+                if docs.is_empty() {
+                    return None;
+                }
+
+                let name = ident.to_string();
+                Some(quote! {
+                    (#name.to_string(), std::collections::BTreeMap::from([#( #docs ),*]))
+                })
+            },
+        );
+
+        let exportable = exportable_methods();
+
+        let handler = match self.phantom() {
+            None => quote! {
+                #[derive(Clone)]
+                #vis struct #handler_ident<P> {
+                    methods: P,
+                }
+            },
+            // `Clone` is implemented manually, the type parameters don't have to be `Clone`.
+            Some(phantom) => quote! {
+                #vis struct #handler_ident<P #( , #params )*> {
+                    methods: P,
+                    _params: #phantom,
+                }
+
+                impl<P: Clone #( , #params )*> Clone for #handler_ident<P #( , #params )*> {
+                    fn clone(&self) -> Self {
+                        Self {
+                            methods: self.methods.clone(),
+                            _params: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+            },
+        };
+
+        quote! {
+            #handler
 
-            use ::tauri::ipc::private::*;
-            impl<R: ::tauri::Runtime, P: #trait_ident + Clone + Send + 'static> taurpc::TauRpcHandler<R> for #handler_ident<P> {
+            impl<R: ::tauri::Runtime, P: #trait_ident #ty_generics + Clone + Send + 'static #( , #param_decls )*> taurpc::TauRpcHandler<R>
+                for #handler_ident<P #( , #params )*> #where_clause
+            {
                 const TRAIT_NAME: &'static str = stringify!(#trait_ident);
                 const PATH_PREFIX: &'static str = #path_prefix;
 
+                #[allow(unused_variables)]
                 fn handle_incoming_request(self, #invoke: tauri::ipc::Invoke<R>) {
+                    let #policy = taurpc::private::invoke_policy(&#invoke);
                     #[allow(unused_variables)]
                     let ::tauri::ipc::Invoke { message: #message, resolver: #resolver, .. } = #invoke;
 
@@ -341,8 +545,8 @@ impl ProceduresGenerator<'_> {
                     let (tx, mut rx) = tokio::sync::broadcast::channel(32);
 
                     tokio::spawn(async move {
-                        while let Ok(invoke) = rx.recv().await {
-                            if let Some(invoke) = std::sync::Arc::into_inner(invoke) {
+                        while let Ok(request) = rx.recv().await {
+                            if let Some(invoke) = std::sync::Arc::into_inner(request) {
                                 self.clone().handle_incoming_request(invoke);
                             }
                         }
@@ -355,18 +559,34 @@ impl ProceduresGenerator<'_> {
                     #serialized_args_map.to_string()
                 }
 
+                fn events() -> Vec<String> {
+                    vec![#( #event_names.to_string() ),*]
+                }
+
+                fn object_args() -> Vec<String> {
+                    vec![#( #object_args_names.to_string() ),*]
+                }
+
+                fn docs() -> taurpc::PathDocs {
+                    taurpc::PathDocs {
+                        docs: #trait_docs.to_string(),
+                        args: std::collections::BTreeMap::from([#( #arg_docs ),*]),
+                    }
+                }
+
+                #[allow(deprecated)]
                 fn collect_fn_types(mut types: &mut specta::Types) -> Vec<specta::datatype::Function> {
-                    specta::function::collect_functions![#( #fn_names ),*](&mut types)
+                    #[allow(unused_mut)]
+                    let mut functions = specta::function::collect_functions![#( #fn_names ),*](&mut types);
+                    #fn_types
+                    functions
                 }
             }
 
-            impl<R: ::tauri::Runtime, P: #trait_ident + Clone + Send + 'static> taurpc::Exportable<R> for #handler_ident<P> {
-                fn generate_types(&self) -> (specta::Types, std::collections::BTreeMap<String, Vec<specta::datatype::Function>>, std::collections::BTreeMap<String, String>) {
-                    let mut types = specta::Types::default();
-                    let fns_map = std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::collect_fn_types(&mut types))]);
-                    let args_map_json = std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::args_map())]);
-                    (types, fns_map, args_map_json)
-                }
+            impl<R: ::tauri::Runtime, P: #trait_ident #ty_generics + Clone + Send + 'static #( , #param_decls )*> taurpc::Exportable<R>
+                for #handler_ident<P #( , #params )*> #where_clause
+            {
+                #exportable
             }
         }
     }
@@ -377,10 +597,12 @@ impl ProceduresGenerator<'_> {
             event_trigger_ident,
             ..
         } = self;
+        let params = self.type_params();
+        let phantom = self.phantom().map(|phantom| quote!(, #phantom));
 
         quote! {
             #[derive(Clone, Debug)]
-            #vis struct #event_trigger_ident<RT: ::tauri::Runtime>(taurpc::EventTrigger<RT>);
+            #vis struct #event_trigger_ident<RT: ::tauri::Runtime #( , #params )*>(taurpc::EventTrigger<RT> #phantom);
         }
     }
 
@@ -391,9 +613,18 @@ impl ProceduresGenerator<'_> {
             methods,
             inputs_ident,
             alias_method_idents,
+            generics,
             ref path_prefix,
             ..
         } = self;
+        let params = self.type_params();
+        let param_decls = generics.params.iter().collect::<Vec<_>>();
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let where_clause = self.where_clause(quote!(+ Clone));
+        let phantom_init = self
+            .phantom()
+            .map(|_| quote!(, ::core::marker::PhantomData));
+        let turbofish = ty_generics.as_turbofish();
 
         let method_triggers = alias_method_idents
             .iter()
@@ -424,7 +655,7 @@ impl ProceduresGenerator<'_> {
                         #[allow(unused)]
                         #vis fn #ident #generics(&self, #( #args ),*) -> tauri::Result<()> {
                             let proc_name = stringify!(#alias_ident);
-                            let req = #inputs_ident::#alias_ident(( #( #arg_pats ),* ));
+                            let req = #inputs_ident #turbofish::#alias_ident(( #( #arg_pats ),* ));
 
                             self.0.call(proc_name, req)
                         }
@@ -434,18 +665,26 @@ impl ProceduresGenerator<'_> {
             .collect::<Vec<_>>();
 
         quote! {
-            impl<RT: ::tauri::Runtime> #event_trigger_ident<RT> {
+            impl<RT: ::tauri::Runtime #( , #param_decls )*> #event_trigger_ident<RT #( , #params )*> #where_clause {
                 /// Generate a new client to trigger events on the client-side.
                 #vis fn new(app_handle: tauri::AppHandle<RT>) -> Self {
                     let trigger = taurpc::EventTrigger::new(app_handle, String::from(#path_prefix));
 
-                    Self(trigger)
+                    Self(trigger #phantom_init)
+                }
+
+                /// Generate a new client to trigger events on the client-side, for the procedures mounted at `path_prefix`
+                /// with `Router::merge_at`.
+                #vis fn new_at(app_handle: tauri::AppHandle<RT>, path_prefix: impl Into<String>) -> Self {
+                    let trigger = taurpc::EventTrigger::new(app_handle, path_prefix.into());
+
+                    Self(trigger #phantom_init)
                 }
 
                 /// Trigger an event with a specific target.
                 #vis fn send_to<I: Into<::tauri::EventTarget>>(&self, target: I) -> Self {
                     let trigger = taurpc::EventTrigger::new_scoped_from_trigger(self.0.clone(), target.into());
-                    Self(trigger)
+                    Self(trigger #phantom_init)
                 }
 
                 #( #method_triggers )*
@@ -468,6 +707,30 @@ impl ToTokens for ProceduresGenerator<'_> {
     }
 }
 
+/// The methods of `taurpc::Exportable` for a handler, which export the handler on its own.
+pub(crate) fn exportable_methods() -> TokenStream2 {
+    quote! {
+        fn generate_types(&self) -> (specta::Types, std::collections::BTreeMap<String, Vec<specta::datatype::Function>>, std::collections::BTreeMap<String, String>) {
+            let mut types = specta::Types::default();
+            let fns_map = std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::collect_fn_types(&mut types))]);
+            let args_map_json = std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::args_map())]);
+            (types, fns_map, args_map_json)
+        }
+
+        fn events(&self) -> std::collections::BTreeMap<String, Vec<String>> {
+            std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::events())])
+        }
+
+        fn object_args(&self) -> std::collections::BTreeMap<String, Vec<String>> {
+            std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::object_args())])
+        }
+
+        fn docs(&self) -> std::collections::BTreeMap<String, taurpc::PathDocs> {
+            std::collections::BTreeMap::from([(<Self as taurpc::TauRpcHandler<R>>::PATH_PREFIX.to_string(), <Self as taurpc::TauRpcHandler<R>>::docs())])
+        }
+    }
+}
+
 fn fn_ident(trait_ident: &Ident, fn_ident: &Ident) -> Ident {
     format_ident!("{trait_ident}_taurpc_fn__{fn_ident}")
 }
@@ -3,16 +3,22 @@ use proc::{IpcMethod, Procedures};
 use proc_macro::{self, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 use syn::{
-    Ident, ImplItem, ImplItemFn, ImplItemType, ItemImpl, ItemStruct, ReturnType, Type,
-    parse_macro_input, parse_quote, parse_quote_spanned, spanned::Spanned,
+    Fields, Ident, ImplItem, ImplItemFn, ImplItemType, Item, ItemConst, ItemFn, ItemImpl,
+    ItemStatic, ReturnType, Type, ext::IdentExt, parse_macro_input, parse_quote,
+    parse_quote_spanned, spanned::Spanned,
 };
 
 mod args;
 mod attrs;
 mod generator;
 mod proc;
+mod procedure;
 
-use crate::attrs::ProceduresAttrs;
+use crate::{
+    args::parse_arg_key,
+    attrs::{IpcTypeAttrs, MethodAttrs, ProceduresAttrs, doc_comments},
+    procedure::CollectProcedures,
+};
 
 /// https://github.com/google/tarpc/blob/master/plugins/src/lib.rs#L29
 /// Accumulates multiple errors into a result.
@@ -28,31 +34,143 @@ macro_rules! extend_errors {
 }
 pub(crate) use extend_errors;
 
-/// Add this macro to all structs used inside the procedures arguments or return types.
+/// Add this macro to all structs and enums used inside the procedures arguments or return types.
 /// This macro is necessary for serialization and TS type generation.
+///
+/// `Clone` is derived as well, unless `clone = false` is set. Extra derives can be added with
+/// `derive(...)`, and `rename_all = "..."` is forwarded to serde. With `skip_none`, named `Option`
+/// fields are not serialized when they are `None`.
 #[proc_macro_attribute]
-pub fn ipc_type(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as ItemStruct);
+pub fn ipc_type(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attrs = parse_macro_input!(attr as IpcTypeAttrs);
+    let mut input = parse_macro_input!(item as Item);
+    let fields: Vec<&mut Fields> = match &mut input {
+        Item::Struct(item) => vec![&mut item.fields],
+        Item::Enum(item) => item.variants.iter_mut().map(|v| &mut v.fields).collect(),
+        _ => {
+            return syn::Error::new(
+                input.span(),
+                "ipc_type can only be used on structs and enums",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+
+    if attrs.skip_none {
+        for field in fields.into_iter().flat_map(|fields| match fields {
+            Fields::Named(named) => named.named.iter_mut().collect(),
+            _ => Vec::new(),
+        }) {
+            if is_option(&field.ty) {
+                field.attrs.push(
+                    parse_quote!(#[serde(default, skip_serializing_if = "::core::option::Option::is_none")]),
+                );
+            }
+        }
+    }
+
+    let mut derives = attrs.derives;
+    if attrs.clone {
+        derives.insert(0, parse_quote!(Clone));
+    }
+    let rename_all = attrs
+        .rename_all
+        .map(|rename_all| quote!(#[serde(rename_all = #rename_all)]));
 
     quote! {
-        #[derive(taurpc::serde::Serialize, taurpc::serde::Deserialize, taurpc::specta::Type, Clone)]
+        #[derive(taurpc::serde::Serialize, taurpc::serde::Deserialize, taurpc::specta::Type #(, #derives)*)]
+        #rename_all
         #input
     }
     .into()
 }
 
+/// Whether the type is spelled as `Option<T>`, type aliases can't be resolved by the macro.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Add this macro to a `const` or `static` to export it with `Exporter::constant`.
+/// This generates a struct with the same name, which is used to reference the constant.
+#[proc_macro_attribute]
+pub fn constant(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as Item);
+
+    let (vis, ident, attrs, value) = match &input {
+        Item::Const(ItemConst {
+            vis, ident, attrs, ..
+        }) => (vis, ident, attrs, quote!(#ident)),
+        // Statics can't be moved out of.
+        Item::Static(ItemStatic {
+            vis, ident, attrs, ..
+        }) => (vis, ident, attrs, quote!(&#ident)),
+        item => {
+            return syn::Error::new(
+                item.span(),
+                "only constants and statics can be exported with `#[taurpc::constant]`",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+
+    let name = ident.unraw().to_string();
+    let docs = doc_comments(attrs).join("\n");
+
+    // The struct has no value, so it doesn't conflict with the constant.
+    quote! {
+        #input
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #ident {}
+
+        impl taurpc::Constant for #ident {
+            const NAME: &'static str = #name;
+            const DOCS: &'static str = #docs;
+
+            fn value() -> impl taurpc::serde::Serialize + taurpc::specta::Type {
+                #value
+            }
+        }
+    }
+    .into()
+}
+
 /// Generates the necessary structs and enums for handling calls and generating TS-types.
 #[proc_macro_attribute]
 pub fn procedures(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let procedures_attrs = parse_macro_input!(attrs as ProceduresAttrs);
 
+    let mut procedures = parse_macro_input!(item as Procedures);
+    if let Some(rule) = procedures_attrs.rename_all {
+        // Arguments that are renamed explicitly keep their name.
+        for arg in procedures
+            .methods
+            .iter_mut()
+            .flat_map(|method| &mut method.args)
+        {
+            if arg.rename.is_none() {
+                arg.rename = parse_arg_key(arg).ok().map(|key| rule.apply(&key));
+            }
+        }
+    }
+
     let Procedures {
         ref ident,
         ref methods,
         ref vis,
         ref generics,
         ref attrs,
-    } = parse_macro_input!(item as Procedures);
+    } = procedures;
 
     let unit_type: &Type = &parse_quote!(());
 
@@ -63,6 +181,7 @@ pub fn procedures(attrs: TokenStream, item: TokenStream) -> TokenStream {
             .event_trigger_ident
             .unwrap_or(format_ident!("TauRpc{}EventTrigger", ident)),
         path_prefix: procedures_attrs.path,
+        args_style: procedures_attrs.args,
         inputs_ident: &format_ident!("TauRpc{}Inputs", ident),
         outputs_ident: &format_ident!("TauRpc{}Outputs", ident),
         output_futures_ident: &format_ident!("TauRpc{}OutputFutures", ident),
@@ -77,11 +196,13 @@ pub fn procedures(attrs: TokenStream, item: TokenStream) -> TokenStream {
         alias_method_idents: &methods
             .iter()
             .map(|IpcMethod { ident, attrs, .. }| {
-                attrs
-                    .alias
-                    .as_ref()
-                    .map(|alias| Ident::new(alias, ident.span()))
-                    .unwrap_or(ident.clone())
+                match (&attrs.alias, procedures_attrs.rename_all) {
+                    (Some(alias), _) => Ident::new(alias, ident.span()),
+                    (None, Some(rule)) => {
+                        Ident::new(&rule.apply(&ident.unraw().to_string()), ident.span())
+                    }
+                    (None, None) => ident.clone(),
+                }
             })
             .collect::<Vec<_>>(),
         vis,
@@ -92,6 +213,34 @@ pub fn procedures(attrs: TokenStream, item: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Add this macro to a free function to use it as a procedure, without a procedures trait.
+/// The function can be async or sync, and takes the same arguments as the methods of a procedures trait.
+/// `alias = "..."` and `args = "object"` are supported, collect the functions into a handler with
+/// `taurpc::collect_procedures!`.
+#[proc_macro_attribute]
+pub fn procedure(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    // The attributes are parsed like the attributes of the methods inside a procedures trait.
+    let attrs = match syn::parse2::<MethodAttrs>(quote!(#[taurpc(#attr)])) {
+        Ok(attrs) => attrs,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let item = parse_macro_input!(item as ItemFn);
+
+    procedure::procedure(attrs, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Collect functions tagged with `#[taurpc::procedure]` into a handler, which can be merged into a router
+/// like the handler of a procedures trait, `taurpc::collect_procedures![path = "files", read_file, write_file]`.
+#[proc_macro]
+pub fn collect_procedures(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as CollectProcedures)
+        .handler()
+        .into()
+}
+
 /// Transforms all methods to return `Pin<Box<Future<Output = ...>>>`, async traits are not supported.
 #[proc_macro_attribute]
 pub fn resolvers(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -1,14 +1,18 @@
 use super::extend_errors;
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
-    Attribute, FnArg, Generics, Ident, Pat, ReturnType, Token, Visibility, braced,
+    Attribute, FnArg, GenericParam, Generics, Ident, Pat, ReturnType, Token, Visibility, braced,
     ext::IdentExt,
     parenthesized,
     parse::{self, Parse, ParseStream},
     spanned::Spanned,
 };
 
-use crate::{args::Arg, attrs::MethodAttrs};
+use crate::{
+    args::{Arg, is_nameable},
+    attrs::MethodAttrs,
+};
 
 /// Parse the structure of the procedures trait tagged with `#[taurpc::procedures]`.
 pub struct Procedures {
@@ -38,7 +42,19 @@ impl Parse for Procedures {
         <Token![trait]>::parse(input)?;
         let ident: Ident = input.parse()?;
 
-        let generics: Generics = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        // The type parameters are instantiated with concrete types when the trait is implemented.
+        if let Some(param) = generics
+            .params
+            .iter()
+            .find(|param| !matches!(param, GenericParam::Type(_)))
+        {
+            return Err(syn::Error::new(
+                param.span(),
+                "procedures traits can only have type parameters",
+            ));
+        }
 
         let content;
         braced!(content in input);
@@ -118,20 +134,10 @@ impl Parse for IpcMethod {
         let content;
         parenthesized!(content in input);
 
-        let mut args = Vec::new();
-        for arg in content.parse_terminated(FnArg::parse, Token![,])? {
-            match arg {
-                FnArg::Typed(pat_ty) if matches!(*pat_ty.pat, Pat::Ident(_)) => {
-                    args.push(Arg::from(pat_ty));
-                }
-                err => {
-                    return Err(syn::Error::new(
-                        err.span(),
-                        "only named arguments are allowed",
-                    ));
-                }
-            }
-        }
+        let args = parse_fn_args(
+            content.parse_terminated(FnArg::parse, Token![,])?,
+            &generics,
+        )?;
 
         let output = input.parse()?;
         <Token![;]>::parse(input)?;
@@ -149,3 +155,35 @@ impl Parse for IpcMethod {
         })
     }
 }
+
+/// Parse the arguments of a procedure, only named arguments are allowed.
+pub(crate) fn parse_fn_args(
+    inputs: impl IntoIterator<Item = FnArg>,
+    generics: &Generics,
+) -> syn::Result<Vec<Arg>> {
+    let mut args = Vec::new();
+    for arg in inputs {
+        match arg {
+            FnArg::Typed(pat_ty) if matches!(*pat_ty.pat, Pat::Ident(_)) => {
+                let arg = Arg::try_from(pat_ty)?;
+                // The default is used in the handler, where the type has to be nameable.
+                if arg.default
+                    && (arg.skip_type || !is_nameable(&arg.ty().to_token_stream(), generics))
+                {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "#[taurpc(default)] is only supported on arguments sent by the frontend, with a concrete type",
+                    ));
+                }
+                args.push(arg);
+            }
+            err => {
+                return Err(syn::Error::new(
+                    err.span(),
+                    "only named arguments are allowed",
+                ));
+            }
+        }
+    }
+    Ok(args)
+}
@@ -0,0 +1,271 @@
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    FnArg, Ident, ItemFn, LitStr, Path, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+};
+
+use crate::{
+    args::{parse_arg_key, parse_args},
+    attrs::{ArgsStyle, MethodAttrs},
+    generator::exportable_methods,
+    proc::parse_fn_args,
+};
+
+/// The hidden struct that implements `taurpc::private::Procedure` for a function tagged with `#[taurpc::procedure]`.
+fn procedure_ident(ident: &Ident) -> Ident {
+    format_ident!("__taurpc_procedure__{}", ident.unraw(), span = ident.span())
+}
+
+/// Generate the implementation of `taurpc::private::Procedure` for a free function.
+pub(crate) fn procedure(attrs: MethodAttrs, mut item: ItemFn) -> syn::Result<TokenStream2> {
+    if attrs.skip || attrs.is_event {
+        return Err(syn::Error::new(
+            item.sig.ident.span(),
+            "`skip` and `event` are only supported inside of a procedures trait",
+        ));
+    }
+
+    let args = parse_fn_args(std::mem::take(&mut item.sig.inputs), &item.sig.generics)?;
+    // The attributes of the arguments are removed by `Arg`, rustc doesn't allow them.
+    item.sig.inputs = args
+        .iter()
+        .map(|arg| -> FnArg { parse_quote!(#arg) })
+        .collect();
+
+    let ItemFn { vis, sig, .. } = &item;
+    let ident = &sig.ident;
+    let output = &sig.output;
+    let struct_ident = procedure_ident(ident);
+    let name = attrs
+        .alias
+        .clone()
+        .unwrap_or_else(|| ident.unraw().to_string());
+    let object_args = attrs.args == Some(ArgsStyle::Object);
+
+    let invoke = format_ident!("__tauri_invoke__");
+    let message = format_ident!("__tauri_message__");
+    let resolver = format_ident!("__tauri_resolver__");
+    let policy = format_ident!("__taurpc_policy__");
+
+    let call_args = parse_args(&args, &message, &policy, ident, &sig.generics)?;
+    let await_call = sig.asyncness.map(|_| quote!(.await));
+
+    let exported_args = args
+        .iter()
+        .filter(|arg| !arg.skip_type)
+        .map(|arg| arg.exported());
+    let keys = args
+        .iter()
+        .filter(|arg| !arg.skip_type)
+        .map(parse_arg_key)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let arg_docs = args
+        .iter()
+        .filter(|arg| !arg.skip_type && !arg.docs.is_empty())
+        .map(|arg| {
+            let key = parse_arg_key(arg)?;
+            let docs = arg.docs.join("\n");
+            Ok(quote! { (#key.to_string(), #docs.to_string()) })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    // Only the attributes that are read by specta are forwarded to the exported function.
+    let export_attrs = item
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("deprecated"));
+
+    Ok(quote! {
+        #item
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #struct_ident;
+
+        impl taurpc::private::Procedure for #struct_ident {
+            const NAME: &'static str = #name;
+            const OBJECT_ARGS: bool = #object_args;
+
+            fn handle<R: ::tauri::Runtime>(#invoke: ::tauri::ipc::Invoke<R>, #policy: taurpc::BigIntPolicy) {
+                #[allow(unused_variables)]
+                let ::tauri::ipc::Invoke { message: #message, resolver: #resolver, .. } = #invoke;
+
+                #[allow(deprecated)]
+                #resolver.respond_async_serialized(async move {
+                    #[allow(unused_imports)]
+                    use taurpc::private::{
+                        RawResponseKind as _, RawResultResponseKind as _, ResultResponseKind as _,
+                        ValueResponseKind as _,
+                    };
+                    let res = #ident(#( #call_args.unwrap() ),*) #await_call;
+                    (&&&taurpc::private::ResponseRef(&res)).taurpc_response_kind().respond(res, #policy)
+                });
+            }
+
+            fn args() -> Vec<String> {
+                vec![#( #keys.to_string() ),*]
+            }
+
+            fn arg_docs() -> std::collections::BTreeMap<String, String> {
+                std::collections::BTreeMap::from([#( #arg_docs ),*])
+            }
+
+            #[allow(deprecated)]
+            fn function(types: &mut specta::Types) -> specta::datatype::Function {
+                #( #export_attrs )*
+                #[specta::specta]
+                #[allow(non_snake_case, unused_variables)]
+                fn #ident( #( #exported_args ),* ) #output {
+                    unimplemented!();
+                }
+
+                specta::function::fn_datatype!(#ident)(types)
+            }
+        }
+    })
+}
+
+/// Parse the input of `taurpc::collect_procedures![path = "...", procedure, ...]`.
+pub(crate) struct CollectProcedures {
+    path: String,
+    procedures: Vec<Path>,
+}
+
+impl Parse for CollectProcedures {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut path = String::new();
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident: Ident = input.parse()?;
+            if ident != "path" {
+                return Err(syn::Error::new(ident.span(), "Unsupported attribute"));
+            }
+            <Token![=]>::parse(input)?;
+            path = input.parse::<LitStr>()?.value();
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+
+        let procedures = Punctuated::<Path, Token![,]>::parse_terminated(input)?;
+
+        Ok(Self {
+            path,
+            procedures: procedures.into_iter().collect(),
+        })
+    }
+}
+
+impl CollectProcedures {
+    /// Generate a handler for the procedures, which can be merged into a router like a procedures trait.
+    pub(crate) fn handler(self) -> TokenStream2 {
+        let Self {
+            path,
+            mut procedures,
+        } = self;
+
+        // There is no trait, the path is used to name the types of the procedures.
+        let trait_name = match path.as_str() {
+            "" => "Procedures".to_string(),
+            path => path.replace('.', "_").to_upper_camel_case(),
+        };
+
+        for procedure in &mut procedures {
+            let segment = procedure.segments.last_mut().unwrap();
+            segment.ident = procedure_ident(&segment.ident);
+        }
+        let procedures = procedures
+            .iter()
+            .map(|procedure| quote!(<#procedure as taurpc::private::Procedure>))
+            .collect::<Vec<_>>();
+        let count = procedures.len();
+        let exportable = exportable_methods();
+
+        quote! {{
+            #[derive(Clone, Copy)]
+            struct TauRpcProceduresHandler;
+
+            impl<R: ::tauri::Runtime> taurpc::TauRpcHandler<R> for TauRpcProceduresHandler {
+                const TRAIT_NAME: &'static str = #trait_name;
+                const PATH_PREFIX: &'static str = #path;
+
+                #[allow(unused_variables)]
+                fn handle_incoming_request(self, invoke: tauri::ipc::Invoke<R>) {
+                    let policy = taurpc::private::invoke_policy(&invoke);
+                    // Remove `TauRpc__` prefix and get the actual name of the command
+                    let command = invoke.message.command()[8..].to_string();
+                    let cmd_name = command.rsplit('.').next().unwrap();
+
+                    #(
+                        if cmd_name == #procedures::NAME {
+                            return #procedures::handle(invoke, policy);
+                        }
+                    )*
+                    invoke.resolver.reject(format!("message `{}` not found", invoke.message.command()));
+                }
+
+                fn spawn(self) -> tokio::sync::broadcast::Sender<std::sync::Arc<tauri::ipc::Invoke<R>>> {
+                    let (tx, mut rx) = tokio::sync::broadcast::channel(32);
+
+                    tokio::spawn(async move {
+                        while let Ok(request) = rx.recv().await {
+                            if let Some(invoke) = std::sync::Arc::into_inner(request) {
+                                self.handle_incoming_request(invoke);
+                            }
+                        }
+                    });
+
+                    tx
+                }
+
+                fn args_map() -> String {
+                    taurpc::private::args_map(std::collections::BTreeMap::from([
+                        #( (#procedures::NAME, #procedures::args()) ),*
+                    ]))
+                }
+
+                fn events() -> Vec<String> {
+                    Vec::new()
+                }
+
+                fn object_args() -> Vec<String> {
+                    let object_args: [(&str, bool); #count] = [#( (#procedures::NAME, #procedures::OBJECT_ARGS) ),*];
+                    object_args
+                        .into_iter()
+                        .filter(|(_, object_args)| *object_args)
+                        .map(|(name, _)| name.to_string())
+                        .collect()
+                }
+
+                fn docs() -> taurpc::PathDocs {
+                    let args: [(String, std::collections::BTreeMap<String, String>); #count] =
+                        [#( (#procedures::NAME.to_string(), #procedures::arg_docs()) ),*];
+                    taurpc::PathDocs {
+                        docs: String::new(),
+                        args: args
+                            .into_iter()
+                            .filter(|(_, docs)| !docs.is_empty())
+                            .collect(),
+                    }
+                }
+
+                fn collect_fn_types(types: &mut specta::Types) -> Vec<specta::datatype::Function> {
+                    vec![#({
+                        let mut function = #procedures::function(types);
+                        function.name = format!("{}_taurpc_fn__{}", #trait_name, #procedures::NAME).into();
+                        function
+                    }),*]
+                }
+            }
+
+            impl<R: ::tauri::Runtime> taurpc::Exportable<R> for TauRpcProceduresHandler {
+                #exportable
+            }
+
+            TauRpcProceduresHandler
+        }}
+    }
+}
@@ -0,0 +1,71 @@
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+/// A naming convention of `rename_all`, used by `#[taurpc::procedures(rename_all = "...")]` and `Exporter::rename_all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum RenameRule {
+    /// `lowercase`
+    LowerCase,
+    /// `UPPERCASE`
+    UpperCase,
+    /// `PascalCase`
+    PascalCase,
+    /// `camelCase`
+    CamelCase,
+    /// `snake_case`
+    SnakeCase,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnakeCase,
+}
+
+impl RenameRule {
+    /// Parse the name of a convention, as it is written in `rename_all = "..."`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lowercase" => Some(Self::LowerCase),
+            "UPPERCASE" => Some(Self::UpperCase),
+            "PascalCase" => Some(Self::PascalCase),
+            "camelCase" => Some(Self::CamelCase),
+            "snake_case" => Some(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnakeCase),
+            _ => None,
+        }
+    }
+
+    /// Convert the name to this convention.
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            Self::LowerCase => name.to_lowercase(),
+            Self::UpperCase => name.to_uppercase(),
+            Self::PascalCase => name.to_upper_camel_case(),
+            Self::CamelCase => name.to_lower_camel_case(),
+            Self::SnakeCase => name.to_snake_case(),
+            Self::ScreamingSnakeCase => name.to_shouty_snake_case(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let cases = [
+            ("lowercase", "user_id"),
+            ("UPPERCASE", "USER_ID"),
+            ("PascalCase", "UserId"),
+            ("camelCase", "userId"),
+            ("snake_case", "user_id"),
+            ("SCREAMING_SNAKE_CASE", "USER_ID"),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(
+                RenameRule::from_name(name).unwrap().apply("user_id"),
+                expected
+            );
+        }
+        assert_eq!(RenameRule::SnakeCase.apply("maxResults"), "max_results");
+        assert_eq!(RenameRule::from_name("kebab-case"), None);
+    }
+}
@@ -0,0 +1,120 @@
+mod ipc;
+
+use serde_json::json;
+use taurpc::Router;
+
+#[taurpc::ipc_type(derive(Copy))]
+struct Point(i32, i32);
+
+#[taurpc::procedures(path = "shapes")]
+trait Shapes {
+    #[taurpc(args = "object")]
+    async fn move_to(point: Point, animate: Option<bool>) -> (Point, Option<bool>);
+}
+
+#[derive(Clone)]
+struct ShapesImpl;
+
+#[taurpc::resolvers]
+impl Shapes for ShapesImpl {
+    async fn move_to(self, point: Point, animate: Option<bool>) -> (Point, Option<bool>) {
+        (point, animate)
+    }
+}
+
+#[taurpc::procedures(path = "users")]
+trait Users {
+    async fn search(
+        query: String,
+        limit: Option<u32>,
+        #[taurpc(default)] exact: bool,
+    ) -> (String, Option<u32>, bool);
+}
+
+#[derive(Clone)]
+struct UsersImpl;
+
+#[taurpc::resolvers]
+impl Users for UsersImpl {
+    async fn search(
+        self,
+        query: String,
+        limit: Option<u32>,
+        exact: bool,
+    ) -> (String, Option<u32>, bool) {
+        (query, limit, exact)
+    }
+}
+
+#[taurpc::procedures(path = "accounts", rename_all = "camelCase")]
+trait Accounts {
+    async fn get_display_name(
+        user_id: u32,
+        #[taurpc(rename = "fallback")] default_name: String,
+    ) -> String;
+}
+
+#[derive(Clone)]
+struct AccountsImpl;
+
+#[taurpc::resolvers]
+impl Accounts for AccountsImpl {
+    async fn get_display_name(self, user_id: u32, default_name: String) -> String {
+        format!("{default_name} {user_id}")
+    }
+}
+
+// The fields of object arguments are sent like positional arguments, the frontend spreads the object.
+#[test]
+fn object_args() {
+    let app = ipc::mock_app(|| Router::new().merge(ShapesImpl.into_handler()));
+
+    assert_eq!(
+        app.invoke(
+            "shapes.move_to",
+            json!({ "point": [1, 2], "animate": true })
+        ),
+        Ok(json!([[1, 2], true]))
+    );
+    assert_eq!(
+        app.invoke("shapes.move_to", json!({ "point": [1, 2] })),
+        Ok(json!([[1, 2], null]))
+    );
+}
+
+#[test]
+fn optional_args() {
+    let app = ipc::mock_app(|| Router::new().merge(UsersImpl.into_handler()));
+
+    assert_eq!(
+        app.invoke(
+            "users.search",
+            json!({ "query": "a", "limit": 5, "exact": true })
+        ),
+        Ok(json!(["a", 5, true]))
+    );
+    assert_eq!(
+        app.invoke(
+            "users.search",
+            json!({ "query": "a", "limit": null, "exact": null })
+        ),
+        Ok(json!(["a", null, false]))
+    );
+    assert_eq!(
+        app.invoke("users.search", json!({ "query": "a" })),
+        Ok(json!(["a", null, false]))
+    );
+}
+
+#[test]
+fn renamed_args() {
+    let app = ipc::mock_app(|| Router::new().merge(AccountsImpl.into_handler()));
+
+    assert_eq!(
+        app.invoke(
+            "accounts.getDisplayName",
+            json!({ "userId": 1, "fallback": "user" })
+        ),
+        Ok(json!("user 1"))
+    );
+}
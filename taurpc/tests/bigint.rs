@@ -0,0 +1,214 @@
+mod ipc;
+
+use serde_json::json;
+use specta::Type;
+use specta::datatype::DataType;
+use tauri::Manager;
+use tauri::ipc::{InvokeResponseBody, IpcResponse, Response};
+use tauri::test::MockRuntime;
+use taurpc::{BigIntExport, BigIntPolicy, Router};
+
+#[taurpc::ipc_type]
+struct Counter {
+    id: u64,
+    step: u32,
+}
+
+/// A response that is sent as it is, without the policy of the router.
+struct RawJson(String);
+
+impl Type for RawJson {
+    fn definition(types: &mut specta::Types) -> DataType {
+        <std::collections::HashMap<String, u64>>::definition(types)
+    }
+}
+
+impl IpcResponse for RawJson {
+    fn body(self) -> tauri::Result<InvokeResponseBody> {
+        Response::new(self.0).body()
+    }
+}
+
+#[taurpc::procedures(path = "counters")]
+trait Counters {
+    async fn add(counter: Counter, amount: i64) -> Counter;
+
+    async fn total(ids: Vec<u64>) -> Result<u128, String>;
+
+    async fn raw(id: u64) -> RawJson;
+
+    async fn try_raw(id: u64) -> Result<RawJson, String>;
+
+    #[taurpc(event)]
+    async fn reached(total: u64);
+}
+
+#[derive(Clone)]
+struct CountersImpl;
+
+#[taurpc::resolvers]
+impl Counters for CountersImpl {
+    async fn add(self, counter: Counter, amount: i64) -> Counter {
+        Counter {
+            id: counter.id.wrapping_add_signed(amount),
+            step: counter.step,
+        }
+    }
+
+    async fn total(self, ids: Vec<u64>) -> Result<u128, String> {
+        Ok(ids.into_iter().map(u128::from).sum())
+    }
+
+    async fn raw(self, id: u64) -> RawJson {
+        RawJson(format!(r#"{{"id":{id}}}"#))
+    }
+
+    async fn try_raw(self, id: u64) -> Result<RawJson, String> {
+        match id {
+            0 => Err("no id".to_string()),
+            id => Ok(RawJson(format!(r#"{{"id":{id}}}"#))),
+        }
+    }
+}
+
+fn router(mode: BigIntExport) -> Router<MockRuntime> {
+    Router::new()
+        .bigint(mode)
+        .merge(CountersImpl.into_handler())
+        .procedure("counters.multiply", |counter: Counter| async move {
+            u128::from(counter.id) * u128::from(counter.step)
+        })
+}
+
+#[test]
+fn numbers() {
+    let app = ipc::mock_app(|| router(BigIntExport::Number));
+
+    assert_eq!(
+        app.invoke(
+            "counters.add",
+            json!({ "counter": { "id": 1, "step": 2 }, "amount": -1 })
+        ),
+        Ok(json!({ "id": 0, "step": 2 }))
+    );
+    assert_eq!(
+        app.invoke("counters.total", json!({ "ids": [1, 2] })),
+        Ok(json!(3))
+    );
+    assert_eq!(
+        app.invoke(
+            "counters.multiply",
+            json!({ "args": { "id": 2, "step": 3 } })
+        ),
+        Ok(json!(6))
+    );
+
+    // Strings are only accepted when the router sends large integers as strings.
+    assert!(
+        app.invoke(
+            "counters.multiply",
+            json!({ "args": { "id": "2", "step": 3 } })
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn guard() {
+    let app = ipc::mock_app(|| router(BigIntExport::Number).bigint_guard(true));
+
+    assert_eq!(
+        app.invoke("counters.total", json!({ "ids": [1, 2] })),
+        Ok(json!(3))
+    );
+    assert!(
+        app.invoke("counters.total", json!({ "ids": [u64::MAX] }))
+            .is_err()
+    );
+}
+
+#[test]
+fn strings() {
+    for mode in [BigIntExport::BigInt, BigIntExport::String] {
+        let app = ipc::mock_app(|| router(mode));
+
+        for id in [json!(u64::MAX - 1), json!((u64::MAX - 1).to_string())] {
+            assert_eq!(
+                app.invoke(
+                    "counters.add",
+                    json!({ "counter": { "id": id, "step": 2 }, "amount": "1" })
+                ),
+                Ok(json!({ "id": u64::MAX.to_string(), "step": 2 }))
+            );
+        }
+        assert_eq!(
+            app.invoke(
+                "counters.total",
+                json!({ "ids": [u64::MAX.to_string(), u64::MAX] })
+            ),
+            Ok(json!((u128::from(u64::MAX) * 2).to_string()))
+        );
+        assert_eq!(
+            app.invoke(
+                "counters.multiply",
+                json!({ "args": { "id": u64::MAX.to_string(), "step": 2 } })
+            ),
+            Ok(json!((u128::from(u64::MAX) * 2).to_string()))
+        );
+
+        // Smaller integers are always sent as numbers.
+        assert!(
+            app.invoke(
+                "counters.multiply",
+                json!({ "args": { "id": 2, "step": "3" } })
+            )
+            .is_err()
+        );
+    }
+}
+
+#[test]
+fn raw_responses() {
+    let app = ipc::mock_app(|| router(BigIntExport::String));
+
+    assert_eq!(
+        app.invoke("counters.raw", json!({ "id": u64::MAX.to_string() })),
+        Ok(json!({ "id": u64::MAX }))
+    );
+    assert_eq!(
+        app.invoke("counters.try_raw", json!({ "id": u64::MAX.to_string() })),
+        Ok(json!({ "id": u64::MAX }))
+    );
+    assert_eq!(
+        app.invoke("counters.try_raw", json!({ "id": 0 })),
+        Err(json!("no id"))
+    );
+}
+
+#[test]
+fn events() {
+    let app = ipc::mock_app(|| router(BigIntExport::String));
+    let trigger = TauRpcCountersEventTrigger::new(app.handle().clone());
+
+    // The router manages its policy when the first procedure is called.
+    assert_eq!(
+        app.invoke("counters.total", json!({ "ids": [1] })),
+        Ok(json!("1"))
+    );
+    trigger.reached(u64::MAX).unwrap();
+    assert_eq!(
+        app.events(),
+        vec![json!({
+            "event_name": "counters.reached",
+            "event": { "proc_name": "reached", "input_type": u64::MAX.to_string() },
+        })]
+    );
+}
+
+#[test]
+fn conflicting_policy() {
+    let app = ipc::mock_app(|| router(BigIntExport::String));
+    app.handle().manage(BigIntPolicy::default());
+
+    assert!(app.invoke("counters.total", json!({ "ids": [1] })).is_err());
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use tauri::test::MockRuntime;
+use taurpc::{Exporter, Router, TypeCollisions};
+
+mod users {
+    #[taurpc::ipc_type]
+    pub struct User {
+        pub name: String,
+    }
+}
+
+mod admin {
+    #[taurpc::ipc_type]
+    pub struct User {
+        pub user: super::users::User,
+        pub permissions: Vec<String>,
+    }
+}
+
+#[taurpc::procedures(path = "accounts")]
+trait Accounts {
+    async fn promote(user: users::User) -> admin::User;
+}
+
+#[derive(Clone)]
+struct AccountsImpl;
+
+#[taurpc::resolvers]
+impl Accounts for AccountsImpl {
+    async fn promote(self, user: users::User) -> admin::User {
+        admin::User {
+            user,
+            permissions: Vec::new(),
+        }
+    }
+}
+
+/// `common::User` collides too, the other types have unique names and keep their name.
+fn router() -> Router<MockRuntime> {
+    common::router().merge(AccountsImpl.into_handler())
+}
+
+#[tokio::test]
+async fn error() {
+    let path = common::output("collisions_error", "bindings.ts");
+    let err = Exporter::new().export(&router(), &path).unwrap_err();
+
+    let err = err.to_string();
+    assert!(
+        err.contains(
+            "`User` is used by `collisions::admin::User`, `collisions::common::User`, `collisions::users::User`"
+        ),
+        "{err}"
+    );
+    assert!(err.contains("`TypeCollisions::ModulePrefix`"), "{err}");
+}
+
+#[tokio::test]
+async fn module_prefix() {
+    let path = common::output("collisions_module_prefix", "bindings.ts");
+    Exporter::new()
+        .type_collisions(TypeCollisions::ModulePrefix)
+        .export(&router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("collisions_module_prefix.ts", path);
+}
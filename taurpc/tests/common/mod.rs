@@ -0,0 +1,190 @@
+//! The router shared by the tests of the exporters, and the snapshots of the exported files.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use tauri::test::MockRuntime;
+use taurpc::Router;
+
+/// A user of the app.
+#[taurpc::ipc_type]
+pub struct User {
+    /// The id of the user.
+    pub uid: u64,
+    pub first_name: String,
+    pub last_name: Option<String>,
+}
+
+#[taurpc::ipc_type(rename_all = "camelCase")]
+pub enum Shape {
+    Circle { radius_px: u32, sample: Sample },
+    Square(u32),
+    Empty,
+}
+
+#[taurpc::ipc_type]
+pub struct Sample {
+    pub label: Option<String>,
+    pub bytes: Vec<u8>,
+    pub buckets: HashMap<u32, Vec<i128>>,
+}
+
+/// Stored in localStorage.
+#[taurpc::ipc_type(rename_all = "camelCase", skip_none)]
+pub struct StoredState {
+    pub last_user: Option<String>,
+    pub point: Point,
+}
+
+#[taurpc::ipc_type(derive(Copy))]
+pub struct Point(i32, i32);
+
+#[taurpc::ipc_type]
+pub struct GreetArgs {
+    pub name: String,
+}
+
+/// The main API.
+#[taurpc::procedures]
+pub trait Api {
+    /// Get a user by id.
+    async fn get_user(
+        /// The id of the user.
+        id: u64,
+    ) -> Result<User, String>;
+
+    #[deprecated(note = "use search")]
+    async fn find(name: String) -> Option<User>;
+
+    async fn search(
+        query: String,
+        #[taurpc(rename = "maxResults")] limit: Option<u32>,
+        #[taurpc(default)] exact: bool,
+    ) -> Vec<User>;
+
+    #[taurpc(args = "object")]
+    async fn move_to(point: Point, animate: Option<bool>) -> Point;
+
+    #[taurpc(alias = "draw")]
+    async fn draw_shape(shape: Shape);
+
+    #[taurpc(event)]
+    async fn user_changed(user: User);
+}
+
+#[derive(Clone)]
+pub struct ApiImpl;
+
+#[taurpc::resolvers]
+impl Api for ApiImpl {
+    async fn get_user(self, id: u64) -> Result<User, String> {
+        Err(format!("user {id} not found"))
+    }
+
+    async fn find(self, _name: String) -> Option<User> {
+        None
+    }
+
+    async fn search(self, _query: String, _limit: Option<u32>, _exact: bool) -> Vec<User> {
+        Vec::new()
+    }
+
+    async fn move_to(self, point: Point, _animate: Option<bool>) -> Point {
+        point
+    }
+
+    async fn draw_shape(self, _shape: Shape) {}
+}
+
+#[taurpc::procedures(path = "events", rename_all = "camelCase")]
+pub trait Events {
+    #[taurpc(event)]
+    async fn state_changed(new_state: StoredState);
+
+    #[taurpc(event)]
+    async fn tick();
+}
+
+#[derive(Clone)]
+pub struct EventsImpl;
+
+#[taurpc::resolvers]
+impl Events for EventsImpl {}
+
+/// A repository of items.
+#[taurpc::procedures(path = "shapes")]
+pub trait Repository<T> {
+    async fn get(#[taurpc(rename = "item_id")] id: u32) -> Option<T>;
+
+    async fn save(item: T) -> Result<(), String>;
+}
+
+#[derive(Clone)]
+pub struct ShapesRepo;
+
+#[taurpc::resolvers]
+impl Repository<Shape> for ShapesRepo {
+    async fn get(self, _id: u32) -> Option<Shape> {
+        None
+    }
+
+    async fn save(self, _item: Shape) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Read a file.
+#[taurpc::procedure]
+pub fn read_file(path: String) -> Result<String, String> {
+    Ok(path)
+}
+
+/// Mount every kind of handler, this has to be called inside of a `tokio` runtime.
+pub fn router() -> Router<MockRuntime> {
+    Router::new()
+        .merge(ApiImpl.into_handler())
+        .merge(EventsImpl.into_handler())
+        .merge(ShapesRepo.into_handler())
+        .merge_at("admin.shapes", ShapesRepo.into_handler())
+        .merge(taurpc::collect_procedures![path = "files", read_file])
+        .procedure("plugins.greet", |args: GreetArgs| async move {
+            format!("Hello, {}!", args.name)
+        })
+}
+
+/// The file at `path` in a directory for the test `name`, the directory is emptied first.
+pub fn output(name: &str, path: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join(path)
+}
+
+/// Compare the file at `path` with the snapshot `name` in `tests/snapshots`.
+/// Run the tests with `TAURPC_UPDATE_SNAPSHOTS=1` to update the snapshots.
+pub fn assert_snapshot(name: &str, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    let actual = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read '{}': {err}", path.display()));
+    let snapshot = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(name);
+
+    if std::env::var_os("TAURPC_UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+        std::fs::write(&snapshot, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&snapshot).unwrap_or_else(|err| {
+        panic!(
+            "failed to read the snapshot '{}': {err}",
+            snapshot.display()
+        )
+    });
+    assert!(
+        actual == expected,
+        "'{}' doesn't match the snapshot '{name}', run the tests with `TAURPC_UPDATE_SNAPSHOTS=1` to update it\n\n{actual}",
+        path.display(),
+    );
+}
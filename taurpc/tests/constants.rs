@@ -0,0 +1,90 @@
+mod common;
+
+use taurpc::{BigIntExport, Exporter};
+
+#[taurpc::ipc_type]
+struct Settings {
+    theme: String,
+    volume: u8,
+    quota: u64,
+}
+
+/// The maximum number of users.
+#[taurpc::constant]
+const MAX_USERS: u32 = 100;
+
+#[taurpc::constant]
+const DEFAULT_SETTINGS: Settings = Settings {
+    theme: String::new(),
+    volume: 5,
+    quota: 1 << 40,
+};
+
+/// Larger than `Number.MAX_SAFE_INTEGER`.
+#[taurpc::constant]
+static MAX_ID: u64 = u64::MAX;
+
+fn exporter() -> Exporter {
+    Exporter::new()
+        .constant::<MAX_USERS>()
+        .constant::<DEFAULT_SETTINGS>()
+}
+
+#[tokio::test]
+async fn typescript() {
+    let path = common::output("constants_typescript", "bindings.ts");
+    exporter().export(&common::router(), &path).unwrap();
+
+    common::assert_snapshot("constants.ts", path);
+}
+
+#[tokio::test]
+async fn javascript() {
+    let path = common::output("constants_javascript", "bindings.js");
+    exporter().export_js(&common::router(), &path).unwrap();
+
+    common::assert_snapshot("constants.js", &path);
+    common::assert_snapshot("constants.d.ts", path.with_extension("d.ts"));
+}
+
+#[tokio::test]
+async fn bigint() {
+    let path = common::output("constants_bigint", "bindings.ts");
+    exporter()
+        .constant::<MAX_ID>()
+        .bigint(BigIntExport::BigInt)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("constants_bigint.ts", path);
+}
+
+#[tokio::test]
+async fn strings() {
+    let path = common::output("constants_strings", "bindings.ts");
+    exporter()
+        .constant::<MAX_ID>()
+        .export(&common::router().bigint(BigIntExport::String), &path)
+        .unwrap();
+
+    common::assert_snapshot("constants_strings.ts", path);
+}
+
+#[tokio::test]
+async fn unsafe_numbers() {
+    let path = common::output("constants_unsafe_numbers", "bindings.ts");
+    let err = exporter()
+        .constant::<MAX_ID>()
+        .export(&common::router(), &path)
+        .unwrap_err();
+
+    let err = err.to_string();
+    assert!(
+        err.contains("failed to serialize the constant `MAX_ID`"),
+        "{err}"
+    );
+    assert!(
+        err.contains("outside of JavaScript's safe integer range"),
+        "{err}"
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use taurpc::{Exporter, Framework};
+
+#[tokio::test]
+async fn react() {
+    let path = common::output("frameworks_react", "bindings.ts");
+    Exporter::new()
+        .framework(Framework::React)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("frameworks_react.ts", path);
+}
+
+#[tokio::test]
+async fn svelte() {
+    let path = common::output("frameworks_svelte", "bindings.ts");
+    Exporter::new()
+        .framework(Framework::Svelte)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("frameworks_svelte.ts", path);
+}
+
+#[tokio::test]
+async fn tanstack_query() {
+    let path = common::output("frameworks_tanstack_query", "bindings.ts");
+    Exporter::new()
+        .framework(Framework::TanstackQuery)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("frameworks_tanstack_query.ts", path);
+}
+
+#[tokio::test]
+async fn all_frameworks() {
+    let path = common::output("frameworks_all", "bindings.ts");
+    Exporter::new()
+        .framework(Framework::React)
+        .framework(Framework::Svelte)
+        .framework(Framework::TanstackQuery)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("frameworks_all.ts", path);
+}
@@ -0,0 +1,78 @@
+//! Call the procedures of a router through the IPC of a mock tauri app.
+// Not every test uses the events.
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tauri::test::{
+    INVOKE_KEY, MockRuntime, get_ipc_response, mock_builder, mock_context, noop_assets,
+};
+use tauri::webview::InvokeRequest;
+use tauri::{App, AppHandle, Listener, Manager, WebviewWindow, WebviewWindowBuilder};
+use taurpc::Router;
+
+pub struct MockApp {
+    webview: WebviewWindow<MockRuntime>,
+    events: Arc<Mutex<Vec<Value>>>,
+    _app: App<MockRuntime>,
+    // The handlers of the router are spawned on this runtime.
+    _runtime: tokio::runtime::Runtime,
+}
+
+/// Create an app with a single window that uses the handler of the router.
+pub fn mock_app(router: impl FnOnce() -> Router<MockRuntime>) -> MockApp {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let router = {
+        let _guard = runtime.enter();
+        router()
+    };
+
+    let app = mock_builder()
+        .invoke_handler(router.into_handler())
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    let webview = WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .unwrap();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let emitted = events.clone();
+    app.listen_any("TauRpc_event", move |event| {
+        let event = serde_json::from_str(event.payload()).unwrap();
+        emitted.lock().unwrap().push(event);
+    });
+
+    MockApp {
+        webview,
+        events,
+        _app: app,
+        _runtime: runtime,
+    }
+}
+
+impl MockApp {
+    /// Call the procedure at `path` with the arguments in `body`, like the frontend does.
+    pub fn invoke(&self, path: &str, body: Value) -> Result<Value, Value> {
+        let request = InvokeRequest {
+            cmd: format!("TauRPC__{path}"),
+            callback: tauri::ipc::CallbackFn(0),
+            error: tauri::ipc::CallbackFn(1),
+            url: "http://tauri.localhost".parse().unwrap(),
+            body: body.into(),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        };
+
+        get_ipc_response(&self.webview, request).map(|body| body.deserialize().unwrap())
+    }
+
+    pub fn handle(&self) -> &AppHandle<MockRuntime> {
+        self.webview.app_handle()
+    }
+
+    /// Take the events that were emitted since the last call, as `{ event_name, event }`.
+    pub fn events(&self) -> Vec<Value> {
+        std::mem::take(&mut self.events.lock().unwrap())
+    }
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use taurpc::{Exporter, Revive};
+
+#[tokio::test]
+async fn jsdoc() {
+    let path = common::output("javascript_jsdoc", "bindings.js");
+    Exporter::new()
+        .revive(Revive::Bytes)
+        .procedure_types(true)
+        .export_js(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("javascript.js", &path);
+    common::assert_snapshot("javascript.d.ts", path.with_extension("d.ts"));
+}
+
+#[tokio::test]
+async fn module_declarations() {
+    let path = common::output("javascript_module_declarations", "bindings.mjs");
+    Exporter::new().export_js(&common::router(), &path).unwrap();
+
+    assert!(path.exists());
+    assert!(path.with_extension("d.mts").exists());
+}
@@ -0,0 +1,15 @@
+mod common;
+
+use taurpc::Exporter;
+
+// The router mounts a generic trait at two paths with an enum that uses `rename_all`,
+// which used to map the types again for every definition and overflow the stack.
+#[tokio::test]
+async fn reference() {
+    let path = common::output("markdown", "api.md");
+    Exporter::new()
+        .export_markdown(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("markdown.md", path);
+}
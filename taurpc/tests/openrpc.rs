@@ -0,0 +1,18 @@
+mod common;
+
+use taurpc::Exporter;
+
+#[tokio::test]
+async fn document() {
+    let path = common::output("openrpc", "openrpc.json");
+    Exporter::new()
+        .openrpc_info("Tests", "1.0.0")
+        .export_openrpc(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("openrpc.json", &path);
+
+    let document: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(document["openrpc"], "1.3.2");
+}
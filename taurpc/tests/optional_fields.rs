@@ -0,0 +1,38 @@
+mod common;
+
+use taurpc::{Exporter, OptionalFields};
+
+#[taurpc::ipc_type]
+struct Profile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    avatar: Option<String>,
+    bio: Option<String>,
+}
+
+async fn export(name: &str, style: OptionalFields) {
+    let path = common::output(name, "bindings.ts");
+    Exporter::new()
+        .optional_fields(style)
+        .with_type::<Profile>()
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot(&format!("{name}.ts"), path);
+}
+
+#[tokio::test]
+async fn nullable() {
+    export("optional_fields_nullable", OptionalFields::Nullable).await;
+}
+
+#[tokio::test]
+async fn optional() {
+    export("optional_fields_optional", OptionalFields::Optional).await;
+}
+
+#[tokio::test]
+async fn undefined() {
+    export("optional_fields_undefined", OptionalFields::Undefined).await;
+}
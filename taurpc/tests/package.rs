@@ -0,0 +1,55 @@
+mod common;
+
+use taurpc::{ClientRuntime, Exporter, Framework, PackageFormat, Validator};
+
+#[tokio::test]
+async fn typescript() {
+    let root = common::output("package_typescript", "");
+    Exporter::new()
+        .validators(Validator::Zod)
+        .framework(Framework::React)
+        .export_package(&common::router(), &root)
+        .unwrap();
+
+    let dir = root.join("node_modules").join(".taurpc");
+    common::assert_snapshot("package_typescript/index.ts", dir.join("index.ts"));
+    common::assert_snapshot("package_typescript/package.json", dir.join("package.json"));
+}
+
+#[tokio::test]
+async fn javascript() {
+    let root = common::output("package_javascript", "");
+    Exporter::new()
+        .package_format(PackageFormat::Javascript)
+        .validators(Validator::Zod)
+        .framework(Framework::React)
+        .export_package(&common::router(), &root)
+        .unwrap();
+
+    let dir = root.join("node_modules").join(".taurpc");
+    common::assert_snapshot("package_javascript/index.js", dir.join("index.js"));
+    common::assert_snapshot("package_javascript/index.d.ts", dir.join("index.d.ts"));
+    common::assert_snapshot("package_javascript/package.json", dir.join("package.json"));
+}
+
+#[tokio::test]
+async fn direct_export() {
+    let path = common::output("package_direct", "node_modules/.taurpc/index.ts");
+    Exporter::new().export(&common::router(), &path).unwrap();
+
+    common::assert_snapshot(
+        "package_typescript/package.json",
+        path.with_file_name("package.json"),
+    );
+}
+
+#[tokio::test]
+async fn javascript_inline_runtime() {
+    let root = common::output("package_javascript_inline_runtime", "");
+    let result = Exporter::new()
+        .package_format(PackageFormat::Javascript)
+        .runtime(ClientRuntime::Inline)
+        .export_package(&common::router(), &root);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,188 @@
+mod ipc;
+
+use std::path::Path;
+
+use serde_json::json;
+use tauri::test::MockRuntime;
+use taurpc::{Exporter, Router};
+
+#[taurpc::ipc_type]
+struct GreetArgs {
+    name: String,
+}
+
+#[taurpc::procedure]
+fn read_file(path: String) -> Result<String, String> {
+    match path.is_empty() {
+        true => Err("the path is empty".to_string()),
+        false => Ok(path),
+    }
+}
+
+#[taurpc::procedure(alias = "write")]
+async fn write_file(path: String, #[taurpc(default)] contents: String) -> String {
+    format!("{path}: {contents}")
+}
+
+#[taurpc::procedures(path = "workspace")]
+trait Workspace {
+    async fn name() -> String;
+
+    #[taurpc(event)]
+    async fn saved(file: String);
+}
+
+#[derive(Clone)]
+struct WorkspaceImpl {
+    name: &'static str,
+}
+
+#[taurpc::resolvers]
+impl Workspace for WorkspaceImpl {
+    async fn name(self) -> String {
+        self.name.to_string()
+    }
+}
+
+#[test]
+fn collected_procedures() {
+    let app = ipc::mock_app(|| {
+        Router::new().merge(taurpc::collect_procedures![
+            path = "files",
+            read_file,
+            write_file
+        ])
+    });
+
+    assert_eq!(
+        app.invoke("files.read_file", json!({ "path": "a.txt" })),
+        Ok(json!("a.txt"))
+    );
+    assert_eq!(
+        app.invoke("files.read_file", json!({ "path": "" })),
+        Err(json!("the path is empty"))
+    );
+    assert_eq!(
+        app.invoke("files.write", json!({ "path": "a.txt", "contents": "a" })),
+        Ok(json!("a.txt: a"))
+    );
+    assert_eq!(
+        app.invoke("files.write", json!({ "path": "a.txt" })),
+        Ok(json!("a.txt: "))
+    );
+}
+
+#[test]
+fn dynamic_procedures() {
+    let app = ipc::mock_app(|| {
+        Router::new()
+            .procedure("plugins.greet", |args: GreetArgs| async move {
+                format!("Hello, {}!", args.name)
+            })
+            .procedure("ping", |_: ()| async { "pong" })
+    });
+
+    assert_eq!(
+        app.invoke("plugins.greet", json!({ "args": { "name": "a" } })),
+        Ok(json!("Hello, a!"))
+    );
+    assert_eq!(app.invoke("ping", json!({})), Ok(json!("pong")));
+    assert!(app.invoke("plugins.greet", json!({})).is_err());
+    assert!(
+        app.invoke("plugins.greet", json!({ "args": { "name": 1 } }))
+            .is_err()
+    );
+}
+
+#[test]
+fn merged_at_prefix() {
+    let app = ipc::mock_app(|| {
+        Router::new()
+            .merge_at(
+                "workspaces.first",
+                WorkspaceImpl { name: "first" }.into_handler(),
+            )
+            .merge_at(
+                "workspaces.second",
+                WorkspaceImpl { name: "second" }.into_handler(),
+            )
+    });
+
+    assert_eq!(
+        app.invoke("workspaces.first.name", json!({})),
+        Ok(json!("first"))
+    );
+    assert_eq!(
+        app.invoke("workspaces.second.name", json!({})),
+        Ok(json!("second"))
+    );
+    // The path of the trait isn't mounted.
+    assert!(app.invoke("workspace.name", json!({})).is_err());
+
+    TauRpcWorkspaceEventTrigger::new_at(app.handle().clone(), "workspaces.second")
+        .saved("a.txt".to_string())
+        .unwrap();
+    assert_eq!(
+        app.events(),
+        vec![json!({
+            "event_name": "workspaces.second.saved",
+            "event": { "proc_name": "saved", "input_type": "a.txt" },
+        })]
+    );
+}
+
+#[test]
+fn optional_unit_args() {
+    let router = Router::<MockRuntime>::new().procedure("ping", |_: ()| async { "pong" });
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("procedures")
+        .join("unit_args.ts");
+    Exporter::new().export(&router, &path).unwrap();
+
+    let bindings = std::fs::read_to_string(path).unwrap();
+    assert!(
+        bindings.contains("ping: (args?: null) => Promise<string>"),
+        "{bindings}"
+    );
+}
+
+fn export_error(router: Router<MockRuntime>) -> String {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("procedures")
+        .join("bindings.ts");
+    Exporter::new()
+        .export(&router, path)
+        .unwrap_err()
+        .to_string()
+}
+
+#[tokio::test]
+async fn invalid_dynamic_procedures() {
+    let greet = |args: GreetArgs| async move { args.name };
+
+    let err = export_error(Router::new().procedure("plugins.", greet));
+    assert!(
+        err.contains("`plugins.` doesn't end with the name of the procedure"),
+        "{err}"
+    );
+
+    let err = export_error(
+        Router::new()
+            .procedure("plugins.greet", greet)
+            .procedure("plugins.greet", greet),
+    );
+    assert!(
+        err.contains("`plugins.greet` is registered more than once"),
+        "{err}"
+    );
+
+    let err = export_error(
+        Router::new()
+            .merge(taurpc::collect_procedures![path = "files", read_file])
+            .procedure("files.read_file", greet),
+    );
+    assert!(
+        err.contains("`files.read_file` is also a procedure of the handler at `files`"),
+        "{err}"
+    );
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use specta::Type;
+use specta::datatype::DataType;
+use taurpc::{Exporter, RuleSet};
+
+#[taurpc::ipc_type]
+struct UserId(String);
+
+#[taurpc::ipc_type]
+struct Document {
+    path: PathBuf,
+    owner: UserId,
+    edited: Vec<UserId>,
+    autosave: Duration,
+}
+
+#[taurpc::procedures(path = "documents")]
+trait Documents {
+    async fn open(path: PathBuf, user: UserId) -> Document;
+}
+
+#[derive(Clone)]
+struct DocumentsImpl;
+
+#[taurpc::resolvers]
+impl Documents for DocumentsImpl {
+    async fn open(self, path: PathBuf, user: UserId) -> Document {
+        Document {
+            path,
+            owner: user,
+            edited: Vec::new(),
+            autosave: Duration::from_secs(60),
+        }
+    }
+}
+
+#[tokio::test]
+async fn remap() {
+    let path = common::output("remap", "bindings.ts");
+    Exporter::new()
+        .remap::<UserId>("string & { readonly __brand: \"UserId\" }")
+        .remap_dt(
+            Duration::definition(&mut Default::default()),
+            DataType::Reference(specta_typescript::define("number")),
+        )
+        .rule_set(RuleSet::Paths)
+        .export(&common::router().merge(DocumentsImpl.into_handler()), &path)
+        .unwrap();
+
+    common::assert_snapshot("remap.ts", path);
+}
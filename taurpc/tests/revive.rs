@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::HashMap;
+
+use taurpc::{Exporter, Revive};
+
+#[taurpc::ipc_type]
+struct Index {
+    files: HashMap<String, Vec<u8>>,
+}
+
+#[taurpc::procedures(path = "library")]
+trait Library {
+    async fn index(roots: Vec<String>) -> Index;
+
+    async fn checksums(index: Index) -> HashMap<u32, Vec<u8>>;
+
+    #[taurpc(event)]
+    async fn indexed(index: Index, sizes: HashMap<String, u32>);
+}
+
+#[derive(Clone)]
+struct LibraryImpl;
+
+#[taurpc::resolvers]
+impl Library for LibraryImpl {
+    async fn index(self, _roots: Vec<String>) -> Index {
+        Index {
+            files: HashMap::new(),
+        }
+    }
+
+    async fn checksums(self, _index: Index) -> HashMap<u32, Vec<u8>> {
+        HashMap::new()
+    }
+}
+
+#[tokio::test]
+async fn maps() {
+    let path = common::output("revive_maps", "bindings.ts");
+    Exporter::new()
+        .revive(Revive::Maps)
+        .revive(Revive::Bytes)
+        .export(&common::router().merge(LibraryImpl.into_handler()), &path)
+        .unwrap();
+
+    common::assert_snapshot("revive_maps.ts", path);
+}
+
+#[cfg(feature = "chrono")]
+mod dates {
+    use chrono::{DateTime, FixedOffset, Utc};
+    use taurpc::{Exporter, Revive};
+
+    #[taurpc::ipc_type]
+    struct Meeting {
+        starts: DateTime<Utc>,
+        attendees: Vec<(String, Option<DateTime<FixedOffset>>)>,
+    }
+
+    #[taurpc::procedures(path = "calendar")]
+    trait Calendar {
+        async fn meetings(after: DateTime<Utc>) -> Vec<Meeting>;
+
+        #[taurpc(event)]
+        async fn moved(meeting: Meeting, from: DateTime<Utc>);
+    }
+
+    #[derive(Clone)]
+    struct CalendarImpl;
+
+    #[taurpc::resolvers]
+    impl Calendar for CalendarImpl {
+        async fn meetings(self, _after: DateTime<Utc>) -> Vec<Meeting> {
+            Vec::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn dates() {
+        let path = super::common::output("revive_dates", "bindings.ts");
+        Exporter::new()
+            .revive(Revive::Dates)
+            .export(
+                &super::common::router().merge(CalendarImpl.into_handler()),
+                &path,
+            )
+            .unwrap();
+
+        super::common::assert_snapshot("revive_dates.ts", path);
+    }
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use taurpc::{ClientRuntime, Exporter};
+
+#[tokio::test]
+async fn inline_typescript() {
+    let path = common::output("runtime_inline", "bindings.ts");
+    Exporter::new()
+        .runtime(ClientRuntime::Inline)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("runtime_inline.ts", path);
+}
+
+#[tokio::test]
+async fn inline_javascript() {
+    let path = common::output("runtime_inline_javascript", "bindings.js");
+    let err = Exporter::new()
+        .runtime(ClientRuntime::Inline)
+        .export_js(&common::router(), &path)
+        .unwrap_err();
+
+    let err = err.to_string();
+    assert!(
+        err.contains("the runtime can only be inlined in TypeScript bindings"),
+        "{err}"
+    );
+    assert!(!path.exists());
+}
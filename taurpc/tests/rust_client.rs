@@ -0,0 +1,35 @@
+mod common;
+
+use taurpc::{BigIntExport, Exporter};
+
+#[tokio::test]
+async fn client() {
+    let path = common::output("rust_client", "bindings.rs");
+    Exporter::new()
+        .export_rust(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("rust_client.rs", path);
+}
+
+#[tokio::test]
+async fn type_paths() {
+    let path = common::output("rust_client_type_paths", "bindings.rs");
+    Exporter::new()
+        .rust_type_path("rust_client::common", "::shared")
+        .export_rust(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("rust_client_type_paths.rs", path);
+}
+
+#[tokio::test]
+async fn bigint_strings() {
+    let path = common::output("rust_client_bigint_strings", "bindings.rs");
+    let err = Exporter::new()
+        .export_rust(&common::router().bigint(BigIntExport::String), &path)
+        .unwrap_err();
+
+    assert!(err.to_string().contains("`BigIntExport::Number`"), "{err}");
+    assert!(!path.exists());
+}
@@ -0,0 +1,109 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: number[] },
+};
+
+export type Settings = {
+	theme: string,
+	volume: number,
+	quota: number,
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+/**
+ * The maximum number of users.
+ */
+export declare const MAX_USERS: number;
+
+export declare const DEFAULT_SETTINGS: Settings;
+
+export declare const createTauRPCProxy: () => ReturnType<typeof createProxy<Router, RouterEvents>>
+export type { InferCommandOutput }
+
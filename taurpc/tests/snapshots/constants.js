@@ -0,0 +1,147 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+/**
+ * @typedef {{
+ * 	name: string,
+ * }} GreetArgs
+ */
+
+/**
+ * @typedef {[number, number]} Point
+ */
+
+/**
+ * @template T, E
+ * @typedef {{
+ * 	ok: T,
+ * 	err: E,
+ * }} Result
+ */
+
+/**
+ * @typedef {{
+ * 	label: string | null,
+ * 	bytes: number[],
+ * 	buckets: { [key in number]: number[] },
+ * }} Sample
+ */
+
+/**
+ * @typedef {{
+ * 	theme: string,
+ * 	volume: number,
+ * 	quota: number,
+ * }} Settings
+ */
+
+/**
+ * @typedef {({ circle: {
+ * 	radius_px: number,
+ * 	sample: Sample,
+ * } }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty"} Shape
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {StoredState_Serialize | StoredState_Deserialize} StoredState
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Deserialize
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Serialize
+ */
+
+/**
+ * A user of the app.
+ * @typedef {{
+ * 	uid: number,
+ * 	first_name: string,
+ * 	last_name: string | null,
+ * }} User
+ */
+
+import { createTauRPCProxy as createProxy } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+/**
+ * @typedef {{
+ * 	"": {
+ * 		draw: (shape: Shape) => Promise<void>,
+ * 		find: (name: string) => Promise<User | null>,
+ * 		get_user: (id: number) => Promise<User>,
+ * 		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+ * 		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+ * 	},
+ * 	"admin.shapes": {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * 	files: {
+ * 		read_file: (path: string) => Promise<string>,
+ * 	},
+ * 	plugins: {
+ * 		greet: (args: GreetArgs) => Promise<string>,
+ * 	},
+ * 	shapes: {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * }} Router
+ */
+/**
+ * @typedef {{
+ * 	"": {
+ * 		user_changed: (user: User) => void,
+ * 	},
+ * 	events: {
+ * 		stateChanged: (newState: StoredState_Serialize) => void,
+ * 		tick: () => void,
+ * 	},
+ * }} RouterEvents
+ */
+
+/**
+ * The maximum number of users.
+ * @type {number}
+ */
+export const MAX_USERS = 100;
+
+/**
+ * @type {Settings}
+ */
+export const DEFAULT_SETTINGS = {
+  "theme": "",
+  "volume": 5,
+  "quota": 1099511627776
+};
+
+/** @type {() => ReturnType<typeof createProxy<Router, RouterEvents>>} */
+export const createTauRPCProxy = () => createProxy(ARGS_MAP, undefined, undefined, OBJECT_ARGS)
+
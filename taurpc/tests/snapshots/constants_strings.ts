@@ -0,0 +1,136 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: string[] },
+};
+
+export type Settings = {
+	theme: string,
+	volume: number,
+	quota: string,
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: string,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: string) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+/**
+ * The maximum number of users.
+ */
+export const MAX_USERS: number = 100;
+
+export const DEFAULT_SETTINGS: Settings = {
+  "theme": "",
+  "volume": 5,
+  "quota": "1099511627776"
+};
+
+/**
+ * Larger than `Number.MAX_SAFE_INTEGER`.
+ */
+export const MAX_ID: string = "18446744073709551615";
+
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(ARGS_MAP, undefined, undefined, OBJECT_ARGS)
+export type { InferCommandOutput }
+
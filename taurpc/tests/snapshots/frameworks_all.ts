@@ -0,0 +1,272 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: number[] },
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(ARGS_MAP, undefined, undefined, OBJECT_ARGS)
+export type { InferCommandOutput }
+
+import type { UnlistenFn } from '@tauri-apps/api/event'
+
+export type Procedures = {
+	"draw": Router[""]["draw"],
+	"find": Router[""]["find"],
+	"get_user": Router[""]["get_user"],
+	"move_to": Router[""]["move_to"],
+	"search": Router[""]["search"],
+	"admin.shapes.get": Router["admin.shapes"]["get"],
+	"admin.shapes.save": Router["admin.shapes"]["save"],
+	"files.read_file": Router["files"]["read_file"],
+	"plugins.greet": Router["plugins"]["greet"],
+	"shapes.get": Router["shapes"]["get"],
+	"shapes.save": Router["shapes"]["save"],
+}
+
+export type Events = {
+	"user_changed": RouterEvents[""]["user_changed"],
+	"events.stateChanged": RouterEvents["events"]["stateChanged"],
+	"events.tick": RouterEvents["events"]["tick"],
+}
+
+export type ProcedureInput<P extends keyof Procedures> = Parameters<Procedures[P]>
+export type ProcedureOutput<P extends keyof Procedures> = Awaited<ReturnType<Procedures[P]>>
+export type ProcedureEventListener<E extends keyof Events> = (...args: Parameters<Events[E]>) => void
+export type ProcedureState<P extends keyof Procedures> = { data?: ProcedureOutput<P>; error?: unknown; isLoading: boolean }
+
+let proxy: ReturnType<typeof createTauRPCProxy> | undefined
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+const getProcedure = (path: string): any =>
+	// eslint-disable-next-line @typescript-eslint/no-explicit-any
+	path.split('.').reduce((layer: any, key) => layer[key], proxy ??= createTauRPCProxy())
+
+/** Call a procedure by its path, e.g. `callProcedure('api.ui.trigger')`. */
+export const callProcedure = <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>): Promise<ProcedureOutput<P>> =>
+	getProcedure(path)(...args)
+
+/** Listen for an event by its path, returns a function to stop listening. */
+export const listenEvent = <E extends keyof Events>(path: E, listener: ProcedureEventListener<E>): Promise<UnlistenFn> =>
+	getProcedure(path).on(listener)
+
+import { useCallback, useEffect, useRef, useState } from 'react'
+
+/** Call a procedure when the component mounts, and again when the path or arguments change. */
+export const useProcedureQuery = <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>): ProcedureState<P> & { refetch: () => Promise<void> } => {
+	const [state, setState] = useState<ProcedureState<P>>({ isLoading: true })
+	const argsRef = useRef(args)
+	argsRef.current = args
+	const key = JSON.stringify([path, args])
+
+	const refetch = useCallback(async () => {
+		setState((state) => ({ ...state, isLoading: true }))
+		try {
+			const data = await callProcedure(path, ...argsRef.current)
+			setState({ data, isLoading: false })
+		} catch (error) {
+			setState({ error, isLoading: false })
+		}
+	}, [key])
+
+	useEffect(() => {
+		void refetch()
+	}, [refetch])
+
+	return { ...state, refetch }
+}
+
+/** Call a procedure on demand with `mutate`. */
+export const useProcedureMutation = <P extends keyof Procedures>(path: P): ProcedureState<P> & { mutate: (...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> } => {
+	const [state, setState] = useState<ProcedureState<P>>({ isLoading: false })
+
+	const mutate = useCallback(async (...args: ProcedureInput<P>) => {
+		setState({ isLoading: true })
+		try {
+			const data = await callProcedure(path, ...args)
+			setState({ data, isLoading: false })
+			return data
+		} catch (error) {
+			setState({ error, isLoading: false })
+			throw error
+		}
+	}, [path])
+
+	return { ...state, mutate }
+}
+
+/** Listen for an event while the component is mounted. */
+export const useEvent = <E extends keyof Events>(path: E, listener: ProcedureEventListener<E>): void => {
+	const listenerRef = useRef(listener)
+	listenerRef.current = listener
+
+	useEffect(() => {
+		const unlisten = listenEvent(path, ((...args: Parameters<Events[E]>) => listenerRef.current(...args)) as ProcedureEventListener<E>)
+		return () => {
+			void unlisten.then((unlisten) => unlisten())
+		}
+	}, [path])
+}
+
+import { readable, writable } from 'svelte/store'
+import type { Readable } from 'svelte/store'
+
+/** A store that calls the procedure once it gets its first subscriber. */
+export const procedureQuery = <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>): Readable<ProcedureState<P>> =>
+	readable<ProcedureState<P>>({ isLoading: true }, (set) => {
+		let active = true
+		callProcedure(path, ...args).then(
+			(data) => active && set({ data, isLoading: false }),
+			(error) => active && set({ error, isLoading: false }),
+		)
+		return () => {
+			active = false
+		}
+	})
+
+/** A store with a `mutate` function to call the procedure on demand. */
+export const procedureMutation = <P extends keyof Procedures>(path: P): Readable<ProcedureState<P>> & { mutate: (...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> } => {
+	const { subscribe, set } = writable<ProcedureState<P>>({ isLoading: false })
+
+	const mutate = async (...args: ProcedureInput<P>) => {
+		set({ isLoading: true })
+		try {
+			const data = await callProcedure(path, ...args)
+			set({ data, isLoading: false })
+			return data
+		} catch (error) {
+			set({ error, isLoading: false })
+			throw error
+		}
+	}
+
+	return { subscribe, mutate }
+}
+
+/** A store containing the arguments of the last event, it stops listening when it has no subscribers left. */
+export const eventStore = <E extends keyof Events>(path: E): Readable<Parameters<Events[E]> | undefined> =>
+	readable<Parameters<Events[E]> | undefined>(undefined, (set) => {
+		const unlisten = listenEvent(path, ((...args: Parameters<Events[E]>) => set(args)) as ProcedureEventListener<E>)
+		return () => {
+			void unlisten.then((unlisten) => unlisten())
+		}
+	})
+
+/** The query key of a procedure, e.g. `['api', 'ui', 'trigger', []]`. Invalidate `['api']` to invalidate all procedures in `api`. */
+export const procedureKey = <P extends keyof Procedures>(path: P, args?: ProcedureInput<P>): (string | ProcedureInput<P>)[] =>
+	args ? [...path.split('.'), args] : path.split('.')
+
+export const procedureQueryOptions = <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>): { queryKey: (string | ProcedureInput<P>)[]; queryFn: () => Promise<ProcedureOutput<P>> } => ({
+	queryKey: procedureKey(path, args),
+	queryFn: () => callProcedure(path, ...args),
+})
+
+export const procedureMutationOptions = <P extends keyof Procedures>(path: P): { mutationKey: (string | ProcedureInput<P>)[]; mutationFn: (args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> } => ({
+	mutationKey: procedureKey(path),
+	mutationFn: (args: ProcedureInput<P>) => callProcedure(path, ...args),
+})
+
@@ -0,0 +1,151 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: Uint8Array,
+	buckets: { [key in number]: number[] },
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+/** The arguments of `draw`. */
+export type Api_DrawInput = [shape: Shape];
+/** The output of `draw`. */
+export type Api_DrawOutput = void;
+/** The arguments of `find`. */
+export type Api_FindInput = [name: string];
+/** The output of `find`. */
+export type Api_FindOutput = User | null;
+/** The arguments of `get_user`. */
+export type Api_GetUserInput = [id: number];
+/** The output of `get_user`. */
+export type Api_GetUserOutput = User;
+/** The arguments of `move_to`. */
+export type Api_MoveToInput = [args: { point: Point; animate?: boolean | null }];
+/** The output of `move_to`. */
+export type Api_MoveToOutput = Point;
+/** The arguments of `search`. */
+export type Api_SearchInput = [query: string, maxResults?: number | null, exact?: boolean | null];
+/** The output of `search`. */
+export type Api_SearchOutput = User[];
+/** The arguments of `user_changed`. */
+export type Api_UserChangedInput = [user: User];
+/** The arguments of `admin.shapes.get`. */
+export type AdminShapes_GetInput = [item_id: number];
+/** The output of `admin.shapes.get`. */
+export type AdminShapes_GetOutput = Shape | null;
+/** The arguments of `admin.shapes.save`. */
+export type AdminShapes_SaveInput = [item: Shape];
+/** The output of `admin.shapes.save`. */
+export type AdminShapes_SaveOutput = null;
+/** The arguments of `events.stateChanged`. */
+export type Events_StateChangedInput = [newState: StoredState_Serialize];
+/** The arguments of `events.tick`. */
+export type Events_TickInput = [];
+/** The arguments of `files.read_file`. */
+export type Files_ReadFileInput = [path: string];
+/** The output of `files.read_file`. */
+export type Files_ReadFileOutput = string;
+/** The arguments of `plugins.greet`. */
+export type Plugins_GreetInput = [args: GreetArgs];
+/** The output of `plugins.greet`. */
+export type Plugins_GreetOutput = string;
+/** The arguments of `shapes.get`. */
+export type Shapes_GetInput = [item_id: number];
+/** The output of `shapes.get`. */
+export type Shapes_GetOutput = Shape | null;
+/** The arguments of `shapes.save`. */
+export type Shapes_SaveInput = [item: Shape];
+/** The output of `shapes.save`. */
+export type Shapes_SaveOutput = null;
+/** The paths of all procedures that can be called. */
+export type ProcedurePath = "draw" | "find" | "get_user" | "move_to" | "search" | "admin.shapes.get" | "admin.shapes.save" | "files.read_file" | "plugins.greet" | "shapes.get" | "shapes.save";
+/** All events, tagged by their path. */
+export type AllEvents = { event: "user_changed"; payload: Api_UserChangedInput } | { event: "events.stateChanged"; payload: Events_StateChangedInput } | { event: "events.tick"; payload: Events_TickInput };
+
+export declare const createTauRPCProxy: () => ReturnType<typeof createProxy<Router, RouterEvents>>
+export type { InferCommandOutput }
+
@@ -0,0 +1,302 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+/**
+ * @typedef {{
+ * 	name: string,
+ * }} GreetArgs
+ */
+
+/**
+ * @typedef {[number, number]} Point
+ */
+
+/**
+ * @template T, E
+ * @typedef {{
+ * 	ok: T,
+ * 	err: E,
+ * }} Result
+ */
+
+/**
+ * @typedef {{
+ * 	label: string | null,
+ * 	bytes: Uint8Array,
+ * 	buckets: { [key in number]: number[] },
+ * }} Sample
+ */
+
+/**
+ * @typedef {({ circle: {
+ * 	radius_px: number,
+ * 	sample: Sample,
+ * } }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty"} Shape
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {StoredState_Serialize | StoredState_Deserialize} StoredState
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Deserialize
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Serialize
+ */
+
+/**
+ * A user of the app.
+ * @typedef {{
+ * 	uid: number,
+ * 	first_name: string,
+ * 	last_name: string | null,
+ * }} User
+ */
+
+import { createTauRPCProxy as createProxy } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const TRANSFORMS = {
+  "procedures": {
+    "": {
+      "draw": {
+        "args": [
+          {
+            "ref": "javascript::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    },
+    "admin.shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "javascript::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "javascript::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    },
+    "shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "javascript::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "javascript::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    }
+  },
+  "types": {
+    "javascript::common::Sample": {
+      "object": {
+        "bytes": "bytes"
+      }
+    },
+    "javascript::common::Shape": {
+      "object": {
+        "circle": {
+          "object": {
+            "sample": {
+              "ref": "javascript::common::Sample"
+            }
+          }
+        }
+      }
+    }
+  }
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+/**
+ * @typedef {{
+ * 	"": {
+ * 		draw: (shape: Shape) => Promise<void>,
+ * 		find: (name: string) => Promise<User | null>,
+ * 		get_user: (id: number) => Promise<User>,
+ * 		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+ * 		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+ * 	},
+ * 	"admin.shapes": {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * 	files: {
+ * 		read_file: (path: string) => Promise<string>,
+ * 	},
+ * 	plugins: {
+ * 		greet: (args: GreetArgs) => Promise<string>,
+ * 	},
+ * 	shapes: {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * }} Router
+ */
+/**
+ * @typedef {{
+ * 	"": {
+ * 		user_changed: (user: User) => void,
+ * 	},
+ * 	events: {
+ * 		stateChanged: (newState: StoredState_Serialize) => void,
+ * 		tick: () => void,
+ * 	},
+ * }} RouterEvents
+ */
+
+/**
+ * The arguments of `draw`.
+ * @typedef {[shape: Shape]} Api_DrawInput
+ */
+/**
+ * The output of `draw`.
+ * @typedef {void} Api_DrawOutput
+ */
+/**
+ * The arguments of `find`.
+ * @typedef {[name: string]} Api_FindInput
+ */
+/**
+ * The output of `find`.
+ * @typedef {User | null} Api_FindOutput
+ */
+/**
+ * The arguments of `get_user`.
+ * @typedef {[id: number]} Api_GetUserInput
+ */
+/**
+ * The output of `get_user`.
+ * @typedef {User} Api_GetUserOutput
+ */
+/**
+ * The arguments of `move_to`.
+ * @typedef {[args: { point: Point; animate?: boolean | null }]} Api_MoveToInput
+ */
+/**
+ * The output of `move_to`.
+ * @typedef {Point} Api_MoveToOutput
+ */
+/**
+ * The arguments of `search`.
+ * @typedef {[query: string, maxResults?: number | null, exact?: boolean | null]} Api_SearchInput
+ */
+/**
+ * The output of `search`.
+ * @typedef {User[]} Api_SearchOutput
+ */
+/**
+ * The arguments of `user_changed`.
+ * @typedef {[user: User]} Api_UserChangedInput
+ */
+/**
+ * The arguments of `admin.shapes.get`.
+ * @typedef {[item_id: number]} AdminShapes_GetInput
+ */
+/**
+ * The output of `admin.shapes.get`.
+ * @typedef {Shape | null} AdminShapes_GetOutput
+ */
+/**
+ * The arguments of `admin.shapes.save`.
+ * @typedef {[item: Shape]} AdminShapes_SaveInput
+ */
+/**
+ * The output of `admin.shapes.save`.
+ * @typedef {null} AdminShapes_SaveOutput
+ */
+/**
+ * The arguments of `events.stateChanged`.
+ * @typedef {[newState: StoredState_Serialize]} Events_StateChangedInput
+ */
+/**
+ * The arguments of `events.tick`.
+ * @typedef {[]} Events_TickInput
+ */
+/**
+ * The arguments of `files.read_file`.
+ * @typedef {[path: string]} Files_ReadFileInput
+ */
+/**
+ * The output of `files.read_file`.
+ * @typedef {string} Files_ReadFileOutput
+ */
+/**
+ * The arguments of `plugins.greet`.
+ * @typedef {[args: GreetArgs]} Plugins_GreetInput
+ */
+/**
+ * The output of `plugins.greet`.
+ * @typedef {string} Plugins_GreetOutput
+ */
+/**
+ * The arguments of `shapes.get`.
+ * @typedef {[item_id: number]} Shapes_GetInput
+ */
+/**
+ * The output of `shapes.get`.
+ * @typedef {Shape | null} Shapes_GetOutput
+ */
+/**
+ * The arguments of `shapes.save`.
+ * @typedef {[item: Shape]} Shapes_SaveInput
+ */
+/**
+ * The output of `shapes.save`.
+ * @typedef {null} Shapes_SaveOutput
+ */
+/**
+ * The paths of all procedures that can be called.
+ * @typedef {"draw" | "find" | "get_user" | "move_to" | "search" | "admin.shapes.get" | "admin.shapes.save" | "files.read_file" | "plugins.greet" | "shapes.get" | "shapes.save"} ProcedurePath
+ */
+/**
+ * All events, tagged by their path.
+ * @typedef {{ event: "user_changed"; payload: Api_UserChangedInput } | { event: "events.stateChanged"; payload: Events_StateChangedInput } | { event: "events.tick"; payload: Events_TickInput }} AllEvents
+ */
+
+/** @type {() => ReturnType<typeof createProxy<Router, RouterEvents>>} */
+export const createTauRPCProxy = () => createProxy(ARGS_MAP, TRANSFORMS, undefined, OBJECT_ARGS)
+
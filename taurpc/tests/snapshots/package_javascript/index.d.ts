@@ -0,0 +1,229 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: number[] },
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+export declare const createTauRPCProxy: () => ReturnType<typeof createProxy<Router, RouterEvents>>
+export type { InferCommandOutput }
+
+import type { UnlistenFn } from '@tauri-apps/api/event'
+
+export type Procedures = {
+	"draw": Router[""]["draw"],
+	"find": Router[""]["find"],
+	"get_user": Router[""]["get_user"],
+	"move_to": Router[""]["move_to"],
+	"search": Router[""]["search"],
+	"admin.shapes.get": Router["admin.shapes"]["get"],
+	"admin.shapes.save": Router["admin.shapes"]["save"],
+	"files.read_file": Router["files"]["read_file"],
+	"plugins.greet": Router["plugins"]["greet"],
+	"shapes.get": Router["shapes"]["get"],
+	"shapes.save": Router["shapes"]["save"],
+}
+
+export type Events = {
+	"user_changed": RouterEvents[""]["user_changed"],
+	"events.stateChanged": RouterEvents["events"]["stateChanged"],
+	"events.tick": RouterEvents["events"]["tick"],
+}
+
+export type ProcedureInput<P extends keyof Procedures> = Parameters<Procedures[P]>
+export type ProcedureOutput<P extends keyof Procedures> = Awaited<ReturnType<Procedures[P]>>
+export type ProcedureEventListener<E extends keyof Events> = (...args: Parameters<Events[E]>) => void
+export type ProcedureState<P extends keyof Procedures> = { data?: ProcedureOutput<P>; error?: unknown; isLoading: boolean }
+
+/** Call a procedure by its path, e.g. `callProcedure('api.ui.trigger')`. */
+export declare const callProcedure: <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>>
+
+/** Listen for an event by its path, returns a function to stop listening. */
+export declare const listenEvent: <E extends keyof Events>(path: E, listener: ProcedureEventListener<E>) => Promise<UnlistenFn>
+
+/** Call a procedure when the component mounts, and again when the path or arguments change. */
+export declare const useProcedureQuery: <P extends keyof Procedures>(path: P, ...args: ProcedureInput<P>) => ProcedureState<P> & { refetch: () => Promise<void> }
+
+/** Call a procedure on demand with `mutate`. */
+export declare const useProcedureMutation: <P extends keyof Procedures>(path: P) => ProcedureState<P> & { mutate: (...args: ProcedureInput<P>) => Promise<ProcedureOutput<P>> }
+
+/** Listen for an event while the component is mounted. */
+export declare const useEvent: <E extends keyof Events>(path: E, listener: ProcedureEventListener<E>) => void
+
+import { z } from 'zod'
+
+export declare const GreetArgsSchema: z.ZodType<GreetArgs>
+
+export declare const PointSchema: z.ZodType<Point>
+
+export declare const ResultSchema: <T extends z.ZodType, E extends z.ZodType>(T: T, E: E) => z.ZodType<Result<z.infer<T>, z.infer<E>>>
+
+export declare const SampleSchema: z.ZodType<Sample>
+
+export declare const ShapeSchema: z.ZodType<Shape>
+
+export declare const StoredStateSchema: z.ZodType<StoredState>
+
+export declare const StoredState_DeserializeSchema: z.ZodType<StoredState_Deserialize>
+
+export declare const StoredState_SerializeSchema: z.ZodType<StoredState_Serialize>
+
+export declare const UserSchema: z.ZodType<User>
+
+export declare const PROCEDURE_SCHEMAS: {
+	"": {
+		draw: {
+			input: z.ZodType<Parameters<Router[""]["draw"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router[""]["draw"]>>>,
+		},
+		find: {
+			input: z.ZodType<Parameters<Router[""]["find"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router[""]["find"]>>>,
+		},
+		get_user: {
+			input: z.ZodType<Parameters<Router[""]["get_user"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router[""]["get_user"]>>>,
+		},
+		move_to: {
+			input: z.ZodType<Parameters<Router[""]["move_to"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router[""]["move_to"]>>>,
+		},
+		search: {
+			input: z.ZodType<Parameters<Router[""]["search"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router[""]["search"]>>>,
+		},
+		user_changed: {
+			input: z.ZodType<Parameters<RouterEvents[""]["user_changed"]>>,
+			output: z.ZodType<Awaited<ReturnType<RouterEvents[""]["user_changed"]>>>,
+		},
+	},
+	"admin.shapes": {
+		get: {
+			input: z.ZodType<Parameters<Router["admin.shapes"]["get"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["admin.shapes"]["get"]>>>,
+		},
+		save: {
+			input: z.ZodType<Parameters<Router["admin.shapes"]["save"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["admin.shapes"]["save"]>>>,
+		},
+	},
+	events: {
+		stateChanged: {
+			input: z.ZodType<Parameters<RouterEvents["events"]["stateChanged"]>>,
+			output: z.ZodType<Awaited<ReturnType<RouterEvents["events"]["stateChanged"]>>>,
+		},
+		tick: {
+			input: z.ZodType<Parameters<RouterEvents["events"]["tick"]>>,
+			output: z.ZodType<Awaited<ReturnType<RouterEvents["events"]["tick"]>>>,
+		},
+	},
+	files: {
+		read_file: {
+			input: z.ZodType<Parameters<Router["files"]["read_file"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["files"]["read_file"]>>>,
+		},
+	},
+	plugins: {
+		greet: {
+			input: z.ZodType<Parameters<Router["plugins"]["greet"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["plugins"]["greet"]>>>,
+		},
+	},
+	shapes: {
+		get: {
+			input: z.ZodType<Parameters<Router["shapes"]["get"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["shapes"]["get"]>>>,
+		},
+		save: {
+			input: z.ZodType<Parameters<Router["shapes"]["save"]>>,
+			output: z.ZodType<Awaited<ReturnType<Router["shapes"]["save"]>>>,
+		},
+	},
+}
+
@@ -0,0 +1,315 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+/**
+ * @typedef {{
+ * 	name: string,
+ * }} GreetArgs
+ */
+
+/**
+ * @typedef {[number, number]} Point
+ */
+
+/**
+ * @template T, E
+ * @typedef {{
+ * 	ok: T,
+ * 	err: E,
+ * }} Result
+ */
+
+/**
+ * @typedef {{
+ * 	label: string | null,
+ * 	bytes: number[],
+ * 	buckets: { [key in number]: number[] },
+ * }} Sample
+ */
+
+/**
+ * @typedef {({ circle: {
+ * 	radius_px: number,
+ * 	sample: Sample,
+ * } }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty"} Shape
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {StoredState_Serialize | StoredState_Deserialize} StoredState
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Deserialize
+ */
+
+/**
+ * Stored in localStorage.
+ * @typedef {{
+ * 	lastUser?: string | null,
+ * 	point: Point,
+ * }} StoredState_Serialize
+ */
+
+/**
+ * A user of the app.
+ * @typedef {{
+ * 	uid: number,
+ * 	first_name: string,
+ * 	last_name: string | null,
+ * }} User
+ */
+
+import { createTauRPCProxy as createProxy } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+/**
+ * @typedef {{
+ * 	"": {
+ * 		draw: (shape: Shape) => Promise<void>,
+ * 		find: (name: string) => Promise<User | null>,
+ * 		get_user: (id: number) => Promise<User>,
+ * 		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+ * 		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+ * 	},
+ * 	"admin.shapes": {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * 	files: {
+ * 		read_file: (path: string) => Promise<string>,
+ * 	},
+ * 	plugins: {
+ * 		greet: (args: GreetArgs) => Promise<string>,
+ * 	},
+ * 	shapes: {
+ * 		get: (item_id: number) => Promise<Shape | null>,
+ * 		save: (item: Shape) => Promise<null>,
+ * 	},
+ * }} Router
+ */
+/**
+ * @typedef {{
+ * 	"": {
+ * 		user_changed: (user: User) => void,
+ * 	},
+ * 	events: {
+ * 		stateChanged: (newState: StoredState_Serialize) => void,
+ * 		tick: () => void,
+ * 	},
+ * }} RouterEvents
+ */
+
+/** @type {() => ReturnType<typeof createProxy<Router, RouterEvents>>} */
+export const createTauRPCProxy = () => createProxy(ARGS_MAP, undefined, undefined, OBJECT_ARGS)
+
+let proxy
+
+const getProcedure = (path) =>
+	path.split('.').reduce((layer, key) => layer[key], proxy ??= createTauRPCProxy())
+
+/** Call a procedure by its path, e.g. `callProcedure('api.ui.trigger')`. */
+export const callProcedure = (path, ...args) =>
+	getProcedure(path)(...args)
+
+/** Listen for an event by its path, returns a function to stop listening. */
+export const listenEvent = (path, listener) =>
+	getProcedure(path).on(listener)
+
+import { useCallback, useEffect, useRef, useState } from 'react'
+
+/** Call a procedure when the component mounts, and again when the path or arguments change. */
+export const useProcedureQuery = (path, ...args) => {
+	const [state, setState] = useState({ isLoading: true })
+	const argsRef = useRef(args)
+	argsRef.current = args
+	const key = JSON.stringify([path, args])
+
+	const refetch = useCallback(async () => {
+		setState((state) => ({ ...state, isLoading: true }))
+		try {
+			const data = await callProcedure(path, ...argsRef.current)
+			setState({ data, isLoading: false })
+		} catch (error) {
+			setState({ error, isLoading: false })
+		}
+	}, [key])
+
+	useEffect(() => {
+		void refetch()
+	}, [refetch])
+
+	return { ...state, refetch }
+}
+
+/** Call a procedure on demand with `mutate`. */
+export const useProcedureMutation = (path) => {
+	const [state, setState] = useState({ isLoading: false })
+
+	const mutate = useCallback(async (...args) => {
+		setState({ isLoading: true })
+		try {
+			const data = await callProcedure(path, ...args)
+			setState({ data, isLoading: false })
+			return data
+		} catch (error) {
+			setState({ error, isLoading: false })
+			throw error
+		}
+	}, [path])
+
+	return { ...state, mutate }
+}
+
+/** Listen for an event while the component is mounted. */
+export const useEvent = (path, listener) => {
+	const listenerRef = useRef(listener)
+	listenerRef.current = listener
+
+	useEffect(() => {
+		const unlisten = listenEvent(path, (...args) => listenerRef.current(...args))
+		return () => {
+			void unlisten.then((unlisten) => unlisten())
+		}
+	}, [path])
+}
+
+import { z } from 'zod'
+
+export const GreetArgsSchema = z.object({
+	name: z.string(),
+})
+
+export const PointSchema = z.tuple([z.number().int(), z.number().int()])
+
+export const ResultSchema = (T, E) => z.object({
+	ok: T,
+	err: E,
+})
+
+export const SampleSchema = z.object({
+	label: z.string().nullable(),
+	bytes: z.array(z.number().int()),
+	buckets: z.record(z.string(), z.array(z.number())),
+})
+
+export const ShapeSchema = z.union([z.object({
+	circle: z.object({
+		radius_px: z.number().int(),
+		sample: z.lazy(() => SampleSchema),
+	}),
+}), z.object({
+	square: z.number().int(),
+}), z.literal("empty")])
+
+export const StoredStateSchema = z.union([z.lazy(() => StoredState_SerializeSchema), z.lazy(() => StoredState_DeserializeSchema)])
+
+export const StoredState_DeserializeSchema = z.object({
+	lastUser: z.string().nullable().optional(),
+	point: z.lazy(() => PointSchema),
+})
+
+export const StoredState_SerializeSchema = z.object({
+	lastUser: z.string().nullable().optional(),
+	point: z.lazy(() => PointSchema),
+})
+
+export const UserSchema = z.object({
+	uid: z.number(),
+	first_name: z.string(),
+	last_name: z.string().nullable(),
+})
+
+export const PROCEDURE_SCHEMAS = {
+	"": {
+		draw: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.void(),
+		},
+		find: {
+			input: z.tuple([z.string()]),
+			output: z.lazy(() => UserSchema).nullable(),
+		},
+		get_user: {
+			input: z.tuple([z.number()]),
+			output: z.lazy(() => UserSchema),
+		},
+		move_to: {
+			input: z.tuple([z.object({
+				point: z.lazy(() => PointSchema),
+				animate: z.boolean().nullable().optional(),
+			})]),
+			output: z.lazy(() => PointSchema),
+		},
+		search: {
+			input: z.tuple([z.string(), z.number().int().nullable().optional(), z.boolean().nullable().optional()]),
+			output: z.array(z.lazy(() => UserSchema)),
+		},
+		user_changed: {
+			input: z.tuple([z.lazy(() => UserSchema)]),
+			output: z.void(),
+		},
+	},
+	"admin.shapes": {
+		get: {
+			input: z.tuple([z.number().int()]),
+			output: z.lazy(() => ShapeSchema).nullable(),
+		},
+		save: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.null(),
+		},
+	},
+	events: {
+		stateChanged: {
+			input: z.tuple([z.lazy(() => StoredState_SerializeSchema)]),
+			output: z.void(),
+		},
+		tick: {
+			input: z.tuple([]),
+			output: z.void(),
+		},
+	},
+	files: {
+		read_file: {
+			input: z.tuple([z.string()]),
+			output: z.string(),
+		},
+	},
+	plugins: {
+		greet: {
+			input: z.tuple([z.lazy(() => GreetArgsSchema)]),
+			output: z.string(),
+		},
+	},
+	shapes: {
+		get: {
+			input: z.tuple([z.number().int()]),
+			output: z.lazy(() => ShapeSchema).nullable(),
+		},
+		save: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.null(),
+		},
+	},
+}
+
@@ -0,0 +1,240 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Index = {
+	files: Map<string, Uint8Array>,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: Uint8Array,
+	buckets: Map<number, number[]>,
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "library": "{\"checksums\":[\"index\"],\"index\":[\"roots\"],\"indexed\":[\"index\",\"sizes\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const TRANSFORMS = {
+  "procedures": {
+    "": {
+      "draw": {
+        "args": [
+          {
+            "ref": "revive::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    },
+    "admin.shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "revive::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "revive::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    },
+    "library": {
+      "checksums": {
+        "args": [
+          {
+            "ref": "revive::Index"
+          }
+        ],
+        "output": {
+          "key": "number",
+          "map": "bytes"
+        }
+      },
+      "index": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "revive::Index"
+        }
+      },
+      "indexed": {
+        "args": [
+          {
+            "ref": "revive::Index"
+          },
+          {
+            "key": null,
+            "map": null
+          }
+        ],
+        "output": null
+      }
+    },
+    "shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "revive::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "revive::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    }
+  },
+  "types": {
+    "revive::Index": {
+      "object": {
+        "files": {
+          "key": null,
+          "map": "bytes"
+        }
+      }
+    },
+    "revive::common::Sample": {
+      "object": {
+        "buckets": {
+          "key": "number",
+          "map": null
+        },
+        "bytes": "bytes"
+      }
+    },
+    "revive::common::Shape": {
+      "object": {
+        "circle": {
+          "object": {
+            "sample": {
+              "ref": "revive::common::Sample"
+            }
+          }
+        }
+      }
+    }
+  }
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	library: {
+		checksums: (index: Index) => Promise<Map<number, Uint8Array>>,
+		index: (roots: string[]) => Promise<Index>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+	library: {
+		indexed: (index: Index, sizes: Map<string, number>) => void,
+	},
+};
+
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(ARGS_MAP, TRANSFORMS, undefined, OBJECT_ARGS)
+export type { InferCommandOutput }
+
@@ -0,0 +1,227 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+#![allow(unused, clippy::all)]
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::{JsCast, JsValue, prelude::*};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = ["window", "__TAURI__", "core"], js_name = invoke)]
+    async fn tauri_invoke(command: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = ["window", "__TAURI__", "event"], js_name = listen)]
+    async fn tauri_listen(
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = Channel)]
+    type TauriChannel;
+
+    #[wasm_bindgen(constructor, js_namespace = ["window", "__TAURI__", "core"], js_class = "Channel")]
+    fn new() -> TauriChannel;
+
+    #[wasm_bindgen(method, setter, js_class = "Channel", js_name = onmessage)]
+    fn set_onmessage(this: &TauriChannel, handler: &Closure<dyn FnMut(JsValue)>);
+}
+
+/// The errors returned by the procedures, `E` is the error type of procedures that return a `Result`.
+#[derive(Debug)]
+pub enum Error<E = std::convert::Infallible> {
+    /// The error returned by the procedure.
+    Procedure(E),
+    /// The command failed, e.g. because the backend couldn't deserialize the arguments.
+    Invoke(JsValue),
+    /// The arguments or the response couldn't be converted.
+    Serde(serde_wasm_bindgen::Error),
+}
+
+impl<E> Error<E> {
+    fn invoke(error: JsValue) -> Self {
+        Self::Invoke(error)
+    }
+}
+
+impl<E: DeserializeOwned> Error<E> {
+    fn procedure(error: JsValue) -> Self {
+        match serde_wasm_bindgen::from_value(error.clone()) {
+            Ok(error) => Self::Procedure(error),
+            Err(_) => Self::Invoke(error),
+        }
+    }
+}
+
+impl<E> From<serde_wasm_bindgen::Error> for Error<E> {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        Self::Serde(error)
+    }
+}
+
+/// Listens for an event until it is dropped.
+pub struct Unlisten {
+    unlisten: js_sys::Function,
+    _handler: Closure<dyn FnMut(JsValue)>,
+}
+
+impl Unlisten {
+    /// Keep listening for the rest of the lifetime of the page.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Unlisten {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&JsValue::NULL);
+    }
+}
+
+fn serialize<T: Serialize, E>(value: &T) -> Result<JsValue, Error<E>> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+async fn call<T: DeserializeOwned, E>(
+    command: &str,
+    args: Vec<(&str, JsValue)>,
+    error: fn(JsValue) -> Error<E>,
+) -> Result<T, Error<E>> {
+    let object = js_sys::Object::new();
+    for (name, value) in args {
+        js_sys::Reflect::set(&object, &JsValue::from_str(name), &value).map_err(Error::Invoke)?;
+    }
+
+    let response = tauri_invoke(command, object.into()).await.map_err(error)?;
+    Ok(serde_wasm_bindgen::from_value(response)?)
+}
+
+fn channel<T: DeserializeOwned + 'static>(mut listener: impl FnMut(T) + 'static) -> JsValue {
+    let channel = TauriChannel::new();
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |message: JsValue| {
+        if let Ok(message) = serde_wasm_bindgen::from_value(message) {
+            listener(message);
+        }
+    });
+    channel.set_onmessage(&handler);
+    // Messages can still be sent after the command returned.
+    handler.forget();
+    channel.into()
+}
+
+async fn listen<T: DeserializeOwned + 'static>(
+    event_name: &'static str,
+    mut listener: impl FnMut(T) + 'static,
+) -> Result<Unlisten, Error> {
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+        let get = |value: &JsValue, key: &str| js_sys::Reflect::get(value, &JsValue::from_str(key));
+        let Ok(payload) = get(&event, "payload") else {
+            return;
+        };
+        if get(&payload, "event_name")
+            .ok()
+            .and_then(|name| name.as_string())
+            .as_deref()
+            != Some(event_name)
+        {
+            return;
+        }
+
+        let input = get(&payload, "event").and_then(|event| get(&event, "input_type"));
+        if let Some(input) = input
+            .ok()
+            .and_then(|input| serde_wasm_bindgen::from_value(input).ok())
+        {
+            listener(input);
+        }
+    });
+
+    let unlisten = tauri_listen("TauRpc_event", &handler)
+        .await
+        .map_err(Error::Invoke)?;
+    Ok(Unlisten {
+        unlisten: unlisten.unchecked_into(),
+        _handler: handler,
+    })
+}
+
+pub async fn draw(shape: ::shared::Shape) -> Result<(), Error> {
+    call("TauRPC__draw", vec![("shape", serialize(&shape)?)], Error::invoke).await
+}
+
+pub async fn find(name: String) -> Result<Option<::shared::User>, Error> {
+    call("TauRPC__find", vec![("name", serialize(&name)?)], Error::invoke).await
+}
+
+/// Get a user by id.
+pub async fn get_user(id: u64) -> Result<::shared::User, Error<String>> {
+    call("TauRPC__get_user", vec![("id", serialize(&id)?)], Error::procedure).await
+}
+
+pub async fn move_to(point: ::shared::Point, animate: Option<bool>) -> Result<::shared::Point, Error> {
+    call("TauRPC__move_to", vec![("point", serialize(&point)?), ("animate", serialize(&animate)?)], Error::invoke).await
+}
+
+pub async fn search(query: String, max_results: Option<u32>, exact: Option<bool>) -> Result<Vec<::shared::User>, Error> {
+    call("TauRPC__search", vec![("query", serialize(&query)?), ("maxResults", serialize(&max_results)?), ("exact", serialize(&exact)?)], Error::invoke).await
+}
+
+pub async fn on_user_changed(mut listener: impl FnMut(::shared::User) + 'static) -> Result<Unlisten, Error> {
+    listen("user_changed", listener).await
+}
+
+pub mod admin {
+    use super::*;
+
+    pub mod shapes {
+        use super::*;
+
+        pub async fn get(item_id: u32) -> Result<Option<::shared::Shape>, Error> {
+            call("TauRPC__admin.shapes.get", vec![("item_id", serialize(&item_id)?)], Error::invoke).await
+        }
+
+        pub async fn save(item: ::shared::Shape) -> Result<(), Error<String>> {
+            call("TauRPC__admin.shapes.save", vec![("item", serialize(&item)?)], Error::procedure).await
+        }
+    }
+}
+
+pub mod events {
+    use super::*;
+
+    pub async fn on_state_changed(mut listener: impl FnMut(::shared::StoredState) + 'static) -> Result<Unlisten, Error> {
+        listen("events.stateChanged", listener).await
+    }
+
+    pub async fn on_tick(mut listener: impl FnMut() + 'static) -> Result<Unlisten, Error> {
+        listen("events.tick", move |(): ()| listener()).await
+    }
+}
+
+pub mod files {
+    use super::*;
+
+    /// Read a file.
+    pub async fn read_file(path: String) -> Result<String, Error<String>> {
+        call("TauRPC__files.read_file", vec![("path", serialize(&path)?)], Error::procedure).await
+    }
+}
+
+pub mod plugins {
+    use super::*;
+
+    pub async fn greet(args: ::shared::GreetArgs) -> Result<String, Error> {
+        call("TauRPC__plugins.greet", vec![("args", serialize(&args)?)], Error::invoke).await
+    }
+}
+
+pub mod shapes {
+    use super::*;
+
+    pub async fn get(item_id: u32) -> Result<Option<::shared::Shape>, Error> {
+        call("TauRPC__shapes.get", vec![("item_id", serialize(&item_id)?)], Error::invoke).await
+    }
+
+    pub async fn save(item: ::shared::Shape) -> Result<(), Error<String>> {
+        call("TauRPC__shapes.save", vec![("item", serialize(&item)?)], Error::procedure).await
+    }
+}
@@ -0,0 +1,234 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: number[] },
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: number,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: number) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(ARGS_MAP, undefined, undefined, OBJECT_ARGS)
+export type { InferCommandOutput }
+
+import * as v from 'valibot'
+
+export const GreetArgsSchema: v.GenericSchema<GreetArgs> = v.object({
+	name: v.string(),
+})
+
+export const PointSchema: v.GenericSchema<Point> = v.tuple([v.pipe(v.number(), v.integer()), v.pipe(v.number(), v.integer())])
+
+export const ResultSchema = <T extends v.GenericSchema, E extends v.GenericSchema>(T: T, E: E) => v.object({
+	ok: T,
+	err: E,
+})
+
+export const SampleSchema: v.GenericSchema<Sample> = v.object({
+	label: v.nullable(v.string()),
+	bytes: v.array(v.pipe(v.number(), v.integer())),
+	buckets: v.record(v.string(), v.array(v.number())),
+})
+
+export const ShapeSchema: v.GenericSchema<Shape> = v.union([v.object({
+	circle: v.object({
+		radius_px: v.pipe(v.number(), v.integer()),
+		sample: v.lazy(() => SampleSchema),
+	}),
+}), v.object({
+	square: v.pipe(v.number(), v.integer()),
+}), v.literal("empty")])
+
+export const StoredStateSchema: v.GenericSchema<StoredState> = v.union([v.lazy(() => StoredState_SerializeSchema), v.lazy(() => StoredState_DeserializeSchema)])
+
+export const StoredState_DeserializeSchema: v.GenericSchema<StoredState_Deserialize> = v.object({
+	lastUser: v.optional(v.nullable(v.string())),
+	point: v.lazy(() => PointSchema),
+})
+
+export const StoredState_SerializeSchema: v.GenericSchema<StoredState_Serialize> = v.object({
+	lastUser: v.optional(v.nullable(v.string())),
+	point: v.lazy(() => PointSchema),
+})
+
+export const UserSchema: v.GenericSchema<User> = v.object({
+	uid: v.number(),
+	first_name: v.string(),
+	last_name: v.nullable(v.string()),
+})
+
+export const PROCEDURE_SCHEMAS = {
+	"": {
+		draw: {
+			input: v.tuple([v.lazy(() => ShapeSchema)]),
+			output: v.void(),
+		},
+		find: {
+			input: v.tuple([v.string()]),
+			output: v.nullable(v.lazy(() => UserSchema)),
+		},
+		get_user: {
+			input: v.tuple([v.number()]),
+			output: v.lazy(() => UserSchema),
+		},
+		move_to: {
+			input: v.tuple([v.object({
+				point: v.lazy(() => PointSchema),
+				animate: v.optional(v.nullable(v.boolean())),
+			})]),
+			output: v.lazy(() => PointSchema),
+		},
+		search: {
+			input: v.tuple([v.string(), v.optional(v.nullable(v.pipe(v.number(), v.integer()))), v.optional(v.nullable(v.boolean()))]),
+			output: v.array(v.lazy(() => UserSchema)),
+		},
+		user_changed: {
+			input: v.tuple([v.lazy(() => UserSchema)]),
+			output: v.void(),
+		},
+	},
+	"admin.shapes": {
+		get: {
+			input: v.tuple([v.pipe(v.number(), v.integer())]),
+			output: v.nullable(v.lazy(() => ShapeSchema)),
+		},
+		save: {
+			input: v.tuple([v.lazy(() => ShapeSchema)]),
+			output: v.null(),
+		},
+	},
+	events: {
+		stateChanged: {
+			input: v.tuple([v.lazy(() => StoredState_SerializeSchema)]),
+			output: v.void(),
+		},
+		tick: {
+			input: v.tuple([]),
+			output: v.void(),
+		},
+	},
+	files: {
+		read_file: {
+			input: v.tuple([v.string()]),
+			output: v.string(),
+		},
+	},
+	plugins: {
+		greet: {
+			input: v.tuple([v.lazy(() => GreetArgsSchema)]),
+			output: v.string(),
+		},
+	},
+	shapes: {
+		get: {
+			input: v.tuple([v.pipe(v.number(), v.integer())]),
+			output: v.nullable(v.lazy(() => ShapeSchema)),
+		},
+		save: {
+			input: v.tuple([v.lazy(() => ShapeSchema)]),
+			output: v.null(),
+		},
+	},
+}
+
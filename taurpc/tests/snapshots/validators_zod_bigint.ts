@@ -0,0 +1,348 @@
+// This file has been generated by TauRPC. Do not edit this file manually.
+
+export type GreetArgs = {
+	name: string,
+};
+
+export type Point = [number, number];
+
+export type Result<T, E> = {
+	ok: T,
+	err: E,
+};
+
+export type Sample = {
+	label: string | null,
+	bytes: number[],
+	buckets: { [key in number]: bigint[] },
+};
+
+export type Shape = ({ circle: {
+	radius_px: number,
+	sample: Sample,
+} }) & { square?: never } | ({ square: number }) & { circle?: never } | "empty";
+
+/**  Stored in localStorage. */
+export type StoredState = StoredState_Serialize | StoredState_Deserialize;
+
+/**  Stored in localStorage. */
+export type StoredState_Deserialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  Stored in localStorage. */
+export type StoredState_Serialize = {
+	lastUser?: string | null,
+	point: Point,
+};
+
+/**  A user of the app. */
+export type User = {
+	/**  The id of the user. */
+	uid: bigint,
+	first_name: string,
+	last_name: string | null,
+};
+
+import { createTauRPCProxy as createProxy, type InferCommandOutput } from 'taurpc'
+const ARGS_MAP = {
+  "": "{\"draw\":[\"shape\"],\"find\":[\"name\"],\"get_user\":[\"id\"],\"move_to\":[\"point\",\"animate\"],\"search\":[\"query\",\"maxResults\",\"exact\"],\"user_changed\":[\"user\"]}",
+  "admin.shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}",
+  "events": "{\"stateChanged\":[\"newState\"],\"tick\":[]}",
+  "files": "{\"read_file\":[\"path\"]}",
+  "plugins": "{\"greet\":[\"args\"]}",
+  "shapes": "{\"get\":[\"item_id\"],\"save\":[\"item\"]}"
+};
+
+const TRANSFORMS = {
+  "procedures": {
+    "": {
+      "draw": {
+        "args": [
+          {
+            "ref": "validators::common::Shape"
+          }
+        ],
+        "output": null
+      },
+      "find": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "validators::common::User"
+        }
+      },
+      "get_user": {
+        "args": [
+          "bigint"
+        ],
+        "output": {
+          "ref": "validators::common::User"
+        }
+      },
+      "search": {
+        "args": [
+          null,
+          null,
+          null
+        ],
+        "output": {
+          "array": {
+            "ref": "validators::common::User"
+          }
+        }
+      },
+      "user_changed": {
+        "args": [
+          {
+            "ref": "validators::common::User"
+          }
+        ],
+        "output": null
+      }
+    },
+    "admin.shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "validators::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "validators::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    },
+    "shapes": {
+      "get": {
+        "args": [
+          null
+        ],
+        "output": {
+          "ref": "validators::common::Shape"
+        }
+      },
+      "save": {
+        "args": [
+          {
+            "ref": "validators::common::Shape"
+          }
+        ],
+        "output": null
+      }
+    }
+  },
+  "types": {
+    "validators::common::Sample": {
+      "object": {
+        "buckets": {
+          "record": {
+            "array": "bigint"
+          }
+        }
+      }
+    },
+    "validators::common::Shape": {
+      "object": {
+        "circle": {
+          "object": {
+            "sample": {
+              "ref": "validators::common::Sample"
+            }
+          }
+        }
+      }
+    },
+    "validators::common::User": {
+      "object": {
+        "uid": "bigint"
+      }
+    }
+  }
+};
+
+const OBJECT_ARGS = {
+  "": {
+    "move_to": [
+      "point",
+      "animate"
+    ]
+  }
+};
+
+export type Router = {
+	/** The main API. */
+	"": {
+		draw: (shape: Shape) => Promise<void>,
+		/**
+		 * @deprecated use search
+		 */
+		find: (name: string) => Promise<User | null>,
+		/**
+		 * Get a user by id.
+		 * @param id The id of the user.
+		 */
+		get_user: (id: bigint) => Promise<User>,
+		move_to: (args: { point: Point; animate?: boolean | null }) => Promise<Point>,
+		search: (query: string, maxResults?: number | null, exact?: boolean | null) => Promise<User[]>,
+	},
+	/** A repository of items. */
+	"admin.shapes": {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+	files: {
+		/** Read a file. */
+		read_file: (path: string) => Promise<string>,
+	},
+	plugins: {
+		greet: (args: GreetArgs) => Promise<string>,
+	},
+	/** A repository of items. */
+	shapes: {
+		get: (item_id: number) => Promise<Shape | null>,
+		save: (item: Shape) => Promise<null>,
+	},
+};
+export type RouterEvents = {
+	/** The main API. */
+	"": {
+		user_changed: (user: User) => void,
+	},
+	events: {
+		stateChanged: (newState: StoredState_Serialize) => void,
+		tick: () => void,
+	},
+};
+
+export const createTauRPCProxy = () => createProxy<Router, RouterEvents>(ARGS_MAP, TRANSFORMS, undefined, OBJECT_ARGS)
+export type { InferCommandOutput }
+
+import { z } from 'zod'
+
+export const GreetArgsSchema: z.ZodType<GreetArgs> = z.object({
+	name: z.string(),
+})
+
+export const PointSchema: z.ZodType<Point> = z.tuple([z.number().int(), z.number().int()])
+
+export const ResultSchema = <T extends z.ZodType, E extends z.ZodType>(T: T, E: E) => z.object({
+	ok: T,
+	err: E,
+})
+
+export const SampleSchema: z.ZodType<Sample> = z.object({
+	label: z.string().nullable(),
+	bytes: z.array(z.number().int()),
+	buckets: z.record(z.string(), z.array(z.bigint())),
+})
+
+export const ShapeSchema: z.ZodType<Shape> = z.union([z.object({
+	circle: z.object({
+		radius_px: z.number().int(),
+		sample: z.lazy(() => SampleSchema),
+	}),
+}), z.object({
+	square: z.number().int(),
+}), z.literal("empty")])
+
+export const StoredStateSchema: z.ZodType<StoredState> = z.union([z.lazy(() => StoredState_SerializeSchema), z.lazy(() => StoredState_DeserializeSchema)])
+
+export const StoredState_DeserializeSchema: z.ZodType<StoredState_Deserialize> = z.object({
+	lastUser: z.string().nullable().optional(),
+	point: z.lazy(() => PointSchema),
+})
+
+export const StoredState_SerializeSchema: z.ZodType<StoredState_Serialize> = z.object({
+	lastUser: z.string().nullable().optional(),
+	point: z.lazy(() => PointSchema),
+})
+
+export const UserSchema: z.ZodType<User> = z.object({
+	uid: z.bigint(),
+	first_name: z.string(),
+	last_name: z.string().nullable(),
+})
+
+export const PROCEDURE_SCHEMAS = {
+	"": {
+		draw: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.void(),
+		},
+		find: {
+			input: z.tuple([z.string()]),
+			output: z.lazy(() => UserSchema).nullable(),
+		},
+		get_user: {
+			input: z.tuple([z.bigint()]),
+			output: z.lazy(() => UserSchema),
+		},
+		move_to: {
+			input: z.tuple([z.object({
+				point: z.lazy(() => PointSchema),
+				animate: z.boolean().nullable().optional(),
+			})]),
+			output: z.lazy(() => PointSchema),
+		},
+		search: {
+			input: z.tuple([z.string(), z.number().int().nullable().optional(), z.boolean().nullable().optional()]),
+			output: z.array(z.lazy(() => UserSchema)),
+		},
+		user_changed: {
+			input: z.tuple([z.lazy(() => UserSchema)]),
+			output: z.void(),
+		},
+	},
+	"admin.shapes": {
+		get: {
+			input: z.tuple([z.number().int()]),
+			output: z.lazy(() => ShapeSchema).nullable(),
+		},
+		save: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.null(),
+		},
+	},
+	events: {
+		stateChanged: {
+			input: z.tuple([z.lazy(() => StoredState_SerializeSchema)]),
+			output: z.void(),
+		},
+		tick: {
+			input: z.tuple([]),
+			output: z.void(),
+		},
+	},
+	files: {
+		read_file: {
+			input: z.tuple([z.string()]),
+			output: z.string(),
+		},
+	},
+	plugins: {
+		greet: {
+			input: z.tuple([z.lazy(() => GreetArgsSchema)]),
+			output: z.string(),
+		},
+	},
+	shapes: {
+		get: {
+			input: z.tuple([z.number().int()]),
+			output: z.lazy(() => ShapeSchema).nullable(),
+		},
+		save: {
+			input: z.tuple([z.lazy(() => ShapeSchema)]),
+			output: z.null(),
+		},
+	},
+}
+
@@ -0,0 +1,37 @@
+mod common;
+
+use taurpc::{BigIntExport, Exporter, Validator};
+
+#[tokio::test]
+async fn zod() {
+    let path = common::output("validators_zod", "bindings.ts");
+    Exporter::new()
+        .validators(Validator::Zod)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("validators_zod.ts", path);
+}
+
+#[tokio::test]
+async fn valibot() {
+    let path = common::output("validators_valibot", "bindings.ts");
+    Exporter::new()
+        .validators(Validator::Valibot)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("validators_valibot.ts", path);
+}
+
+#[tokio::test]
+async fn zod_bigint() {
+    let path = common::output("validators_zod_bigint", "bindings.ts");
+    Exporter::new()
+        .validators(Validator::Zod)
+        .bigint(BigIntExport::BigInt)
+        .export(&common::router(), &path)
+        .unwrap();
+
+    common::assert_snapshot("validators_zod_bigint.ts", path);
+}